@@ -0,0 +1,168 @@
+use std::path::PathBuf;
+use anyhow::{Result, anyhow};
+use chrono::{Datelike, Local, NaiveDate};
+use clap::{Parser, Subcommand};
+
+use shared::{data::load_database, definitions::{Entry, Semester}, selection::Selection};
+
+pub mod resolve;
+pub mod output;
+
+use resolve::{resolve_classroom, resolve_program, resolve_subject, resolve_teacher, ProgramFilter};
+
+
+/// Query the timetable from the terminal
+#[derive(Parser)]
+#[command(name = "timetable-cli")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Directory with timetable.bin or *.json data
+    #[arg(long, global = true, default_value = "static")]
+    data: PathBuf,
+
+    /// Main program (MAG-1) or program abbreviation/id
+    #[arg(long, short, global = true)]
+    program: Option<String>,
+
+    #[arg(long, short, global = true)]
+    semester: Option<u8>,
+
+    /// Subject abbreviation, can be repeated
+    #[arg(long = "subject", global = true)]
+    subjects: Vec<String>,
+
+    /// Teacher name or part of it
+    #[arg(long, short, global = true)]
+    teacher: Option<String>,
+
+    /// Classroom name or part of it
+    #[arg(long, short, global = true)]
+    classroom: Option<String>,
+
+    /// Selection file with subjects and groups (default: ~/.config/timetable/selection.json)
+    #[arg(long, global = true)]
+    selection: Option<PathBuf>,
+
+    /// Ignore the default selection file
+    #[arg(long, global = true)]
+    no_selection: bool,
+
+    /// Print JSON instead of a table
+    #[arg(long, global = true)]
+    json: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Entries of a date, week or everything matching the filters
+    List {
+        /// Date as YYYY-MM-DD
+        #[arg(long)]
+        date: Option<NaiveDate>,
+        /// ISO week number
+        #[arg(long)]
+        week: Option<i32>,
+        #[arg(long)]
+        year: Option<i32>,
+    },
+    /// Today's entries
+    Today,
+    /// The next entry from now on
+    Next,
+    /// Entries of the current ISO week
+    Week,
+}
+
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let db = load_database(&cli.data)
+        .map_err(|e| anyhow!("Cannot load data from {}: {e}", cli.data.display()))?;
+
+    let program = cli.program.as_deref().map(|p| resolve_program(&db, p)).transpose()?;
+    let subjects = cli.subjects
+        .iter()
+        .map(|abbr| resolve_subject(&db, abbr))
+        .collect::<Result<Vec<_>>>()?;
+    let teacher = cli.teacher.as_deref().map(|t| resolve_teacher(&db, t)).transpose()?;
+    let classroom = cli.classroom.as_deref().map(|c| resolve_classroom(&db, c)).transpose()?;
+    let selection = load_selection(&cli)?;
+
+    let now = Local::now().naive_local();
+    let today = now.date();
+    let (date, week, year) = match cli.command {
+        None => (None, None, None),
+        Some(Command::List { date, week, year }) => (date, week, year),
+        Some(Command::Today) => (Some(today), None, None),
+        Some(Command::Next) => (None, None, None),
+        Some(Command::Week) => (None, Some(today.iso_week().week() as i32), Some(today.iso_week().year())),
+    };
+
+    let mut query = db.query();
+    match &program {
+        Some(ProgramFilter::Main(id)) => query = query.main_program(id),
+        Some(ProgramFilter::Program(id)) => query = query.program(id),
+        None => {}
+    }
+    if let Some(semester) = cli.semester {
+        query = query.semester(Semester(semester));
+    }
+    if let Some(teacher) = &teacher {
+        query = query.teacher(teacher);
+    }
+    if let Some(classroom) = &classroom {
+        query = query.classroom(classroom);
+    }
+    if let Some(date) = date {
+        query = query.date(date);
+    }
+    if let Some(week) = week {
+        query = query.week(week);
+    }
+    if let Some(year) = year {
+        query = query.year(year);
+    }
+
+    let mut entries: Vec<&Entry> = query
+        .run()
+        .into_iter()
+        .filter(|e| subjects.is_empty() || subjects.contains(&e.subject_id))
+        .filter(|e| selection.as_ref().is_none_or(|s| s.contains(e)))
+        .collect();
+    entries.sort_by_key(|e| (e.start_datetime(), e.subject_id));
+
+    if matches!(cli.command, Some(Command::Next)) {
+        entries.retain(|e| e.start_datetime() > now);
+        entries.truncate(1);
+        if let (Some(next), false) = (entries.first(), cli.json) {
+            let minutes = (next.start_datetime() - now).num_minutes();
+            println!("Next in {}h {:02}min", minutes / 60, minutes % 60);
+        }
+    }
+
+    if cli.json {
+        println!("{}", serde_json::to_string_pretty(&output::to_json(&db, &entries))?);
+    } else {
+        output::print_table(&db, &entries);
+    }
+
+    Ok(())
+}
+
+/// Selection given with --selection, otherwise the default file if it exists
+fn load_selection(cli: &Cli) -> Result<Option<Selection>> {
+    if let Some(path) = &cli.selection {
+        return Selection::load(path)
+            .map(Some)
+            .map_err(|e| anyhow!("Cannot read selection {}: {e}", path.display()));
+    }
+    if cli.no_selection {
+        return Ok(None);
+    }
+    match Selection::default_path() {
+        Some(path) if path.exists() => Ok(Some(Selection::load(&path)?)),
+        _ => Ok(None),
+    }
+}
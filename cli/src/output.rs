@@ -0,0 +1,128 @@
+use std::io::IsTerminal;
+use chrono::Datelike;
+use serde_json::{json, Value};
+
+use shared::{db::Database, definitions::{Entry, EntryType}};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const CYAN: &str = "\x1b[36m";
+const YELLOW: &str = "\x1b[33m";
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+struct Row {
+    cells: Vec<String>,
+    lecture: bool,
+}
+
+fn teacher_names(db: &Database, entry: &Entry) -> Vec<String> {
+    let mut names: Vec<String> = entry.teacher_ids
+        .iter()
+        .filter_map(|id| db.teachers.get(id))
+        .map(|t| t.name.clone())
+        .collect();
+    names.sort();
+    names
+}
+
+fn rows(db: &Database, entries: &[&Entry]) -> Vec<Row> {
+    entries
+        .iter()
+        .map(|e| {
+            let subject = db.subjects.get(&e.subject_id).map(|s| s.abbr.clone()).unwrap_or_default();
+            let classroom = db.classrooms.get(&e.classroom_id).map(|c| c.full_name.clone()).unwrap_or_default();
+            Row {
+                cells: vec![
+                    e.date.format("%d.%m.%Y").to_string(),
+                    WEEKDAYS[e.date.weekday().num_days_from_monday() as usize].to_string(),
+                    format!("{}-{}", e.start_time.format("%H:%M"), e.end_time().format("%H:%M")),
+                    subject,
+                    e.entry_type.to_string(),
+                    classroom,
+                    teacher_names(db, e).join(", "),
+                ],
+                lecture: e.entry_type == EntryType::Lecture,
+            }
+        })
+        .collect()
+}
+
+/// Aligned table, colored when stdout is a terminal and NO_COLOR isn't set
+pub fn print_table(db: &Database, entries: &[&Entry]) {
+    if entries.is_empty() {
+        println!("No entries.");
+        return;
+    }
+
+    let color = std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+    let paint = |code: &str, text: &str| {
+        if color { format!("{code}{text}{RESET}") } else { text.to_string() }
+    };
+
+    let header = ["Date", "Day", "Time", "Subject", "Type", "Classroom", "Teachers"];
+    let rows = rows(db, entries);
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(&row.cells) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let pad = |text: &str, width: usize| format!("{text}{}", " ".repeat(width - text.chars().count()));
+
+    let header_line = header
+        .iter()
+        .zip(&widths)
+        .map(|(h, w)| pad(h, *w))
+        .collect::<Vec<_>>()
+        .join("  ");
+    println!("{}", paint(BOLD, header_line.trim_end()));
+
+    let mut previous_date = None;
+    for row in &rows {
+        // Dim repeated dates so days stand out
+        let same_day = previous_date == Some(&row.cells[0]);
+        previous_date = Some(&row.cells[0]);
+
+        let line = row.cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, w))| {
+                let text = pad(cell, *w);
+                match i {
+                    0 | 1 if same_day => paint(DIM, &text),
+                    3 => paint(CYAN, &text),
+                    4 if !row.lecture => paint(YELLOW, &text),
+                    _ => text,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+}
+
+pub fn to_json(db: &Database, entries: &[&Entry]) -> Value {
+    Value::Array(entries
+        .iter()
+        .map(|e| {
+            let subject = db.subjects.get(&e.subject_id);
+            json!({
+                "id": e.id,
+                "subject_id": e.subject_id,
+                "subject": subject.map(|s| s.name.clone()),
+                "subject_abbr": subject.map(|s| s.abbr.clone()),
+                "entry_type": e.entry_type.to_string(),
+                "date": e.date,
+                "start_time": e.start_time.format("%H:%M").to_string(),
+                "end_time": e.end_time().format("%H:%M").to_string(),
+                "duration": e.duration,
+                "classroom": db.classrooms.get(&e.classroom_id).map(|c| c.full_name.clone()),
+                "teachers": teacher_names(db, e),
+            })
+        })
+        .collect())
+}
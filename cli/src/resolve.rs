@@ -0,0 +1,65 @@
+use anyhow::{Result, anyhow};
+
+use shared::{db::Database, definitions::{ClassroomId, MainProgramId, ProgramId, SubjectId, TeacherId}};
+
+pub enum ProgramFilter {
+    Main(MainProgramId),
+    Program(ProgramId),
+}
+
+/// Picks the exact (case-insensitive) match, otherwise the only one containing `needle`
+fn find_one<'a, T: Copy>(kind: &str, needle: &str, candidates: impl Iterator<Item = (T, &'a str)>) -> Result<T> {
+    let needle_lower = needle.to_lowercase();
+    let mut matching = Vec::new();
+    for (id, name) in candidates {
+        let name_lower = name.to_lowercase();
+        if name_lower == needle_lower {
+            return Ok(id);
+        }
+        if name_lower.contains(&needle_lower) {
+            matching.push((id, name));
+        }
+    }
+
+    match matching.len() {
+        0 => Err(anyhow!("No {kind} matches '{needle}'")),
+        1 => Ok(matching[0].0),
+        _ => {
+            let mut names: Vec<&str> = matching.iter().map(|(_, name)| *name).collect();
+            names.sort();
+            Err(anyhow!("'{needle}' matches several {kind}s: {}", names.join(", ")))
+        }
+    }
+}
+
+pub fn resolve_program(db: &Database, needle: &str) -> Result<ProgramFilter> {
+    if let Some(mp) = db.main_programs.values().find(|mp| mp.name.eq_ignore_ascii_case(needle)) {
+        return Ok(ProgramFilter::Main(mp.id));
+    }
+    if let Ok(id) = needle.parse::<u32>() {
+        if db.programs.contains_key(&ProgramId(id)) {
+            return Ok(ProgramFilter::Program(ProgramId(id)));
+        }
+    }
+    if let Some(p) = db.programs.values().find(|p| p.abbr.eq_ignore_ascii_case(needle)) {
+        return Ok(ProgramFilter::Program(p.id));
+    }
+    find_one("program", needle, db.programs.values().map(|p| (p.id, p.full_name.as_str())))
+        .map(ProgramFilter::Program)
+}
+
+pub fn resolve_subject(db: &Database, abbr: &str) -> Result<SubjectId> {
+    db.subjects
+        .values()
+        .find(|s| s.abbr.eq_ignore_ascii_case(abbr))
+        .map(|s| s.id)
+        .ok_or_else(|| anyhow!("No subject with abbreviation '{abbr}'"))
+}
+
+pub fn resolve_teacher(db: &Database, name: &str) -> Result<TeacherId> {
+    find_one("teacher", name, db.teachers.values().map(|t| (t.id, t.name.as_str())))
+}
+
+pub fn resolve_classroom(db: &Database, name: &str) -> Result<ClassroomId> {
+    find_one("classroom", name, db.classrooms.values().map(|c| (c.id, c.full_name.as_str())))
+}
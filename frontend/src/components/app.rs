@@ -1,20 +1,46 @@
+use yew::platform::spawn_local;
 use yew::prelude::*;
-use std::collections::HashMap;
-use chrono::{Datelike, NaiveDate};
+use std::collections::{BTreeSet, HashMap};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use gloo::events::EventListener;
 
-use shared::definitions::{MainProgramId, Semester, Subject, SubjectId};
+use shared::csv::CsvWriter;
+use shared::ics::{self, Event};
+use shared::search::SearchHit;
+use shared::time::semester_mondays;
+use shared::definitions::{ClassroomId, EntryId, MainProgram, MainProgramId, Program, ProgramId, Semester, Subject, SubjectId, TeacherId};
 
-use crate::components::entry_box::entry_to_props;
+use crate::components::entry_box::EntryBoxProps;
+use crate::components::entry_detail::{EntryDetail, EntryDetailPanel};
 use crate::components::header::Header;
+use crate::components::month_view::MonthView;
+use crate::components::semester_view::SemesterView;
 use crate::components::calendar::Calendar;
+use crate::components::day_view::DayView;
+use crate::components::my_group::MyGroup;
+use crate::components::overrides_list::{override_labels, OverridesList};
+use crate::components::personal_events::PersonalEvents;
+use crate::components::print_legend::PrintLegend;
 use crate::components::subject_abbr_container::SubjectAbbrContainer;
 use crate::components::subject_select_container::SubjectContainer;
 use crate::components::timetable::Timetable;
+use crate::components::update_banner::UpdateBanner;
+use crate::components::view_banner::ViewBanner;
 use crate::database::FrontendDatabase;
-use crate::logic::entries::{arrange_columns, filter_entries};
-use crate::logic::visibility::{toggle_group, toggle_subject, SubjectVisibility, SubjectsVisibilityMap};
-use crate::logic::date::current_iso_week;
-use crate::utils::{create_multiple_colors, Color};
+use crate::export::{self, ExportFormat, WeekPage};
+use crate::i18n::Lang;
+use crate::logic::entries::{arrange_columns, mark_clashes, EntrySource};
+use crate::logic::colors::{Palette, SubjectColors};
+use crate::logic::theme::Theme;
+use crate::logic::saved::{load_selection, save_selection, SavedSelection};
+use crate::logic::personal::{load_personal_events, save_personal_events, PersonalEvent};
+use crate::logic::overrides::{alternatives, is_shown, OverrideAction, OverrideItem, Overrides, Series};
+use crate::logic::profile::Profile;
+use crate::logic::visibility::{apply_profile, basket, toggle_group, toggle_subject, SubjectVisibility, SubjectsVisibilityMap};
+use crate::logic::date::{add_months, current_iso_week, month_mondays, month_of_week, now, step_workday};
+use crate::logic::view::{DisplayMode, View};
+use crate::pwa::{newer_data_available, register_service_worker};
+use crate::utils::{download_bytes, download_text, is_narrow_screen, Color};
 
 
 
@@ -22,15 +48,89 @@ use crate::utils::{create_multiple_colors, Color};
 #[function_component(App)]
 pub fn app() -> Html {
     
+    let lang = use_state(Lang::detect);
+    let on_lang_change = {
+        let lang = lang.clone();
+        Callback::from(move |new_lang: Lang| {
+            new_lang.save();
+            lang.set(new_lang);
+        })
+    };
+
+    // keep <html lang> in sync for screen readers and hyphenation
+    use_effect_with(*lang, |lang| {
+        if let Some(html) = web_sys::window().and_then(|w| w.document()).and_then(|d| d.document_element()) {
+            let _ = html.set_attribute("lang", lang.code());
+        }
+    });
+
+    let theme = use_state(Theme::load);
+    let on_theme_change = {
+        let theme = theme.clone();
+        Callback::from(move |new_theme: Theme| {
+            new_theme.save();
+            theme.set(new_theme);
+        })
+    };
+    use_effect_with(*theme, |theme| theme.apply());
+
+    // Service worker, and a prompt when the server has newer data (checked on start and when back online)
+    let update_available = use_state(|| false);
+    {
+        let update_available = update_available.clone();
+        use_effect_with((), move |_| {
+            register_service_worker();
+            let check = move || {
+                let update_available = update_available.clone();
+                spawn_local(async move {
+                    if newer_data_available().await {
+                        update_available.set(true);
+                    }
+                });
+            };
+            check();
+            let listener = EventListener::new(&web_sys::window().unwrap(), "online", move |_| check());
+            move || drop(listener)
+        });
+    }
+    let on_dismiss_update = {
+        let update_available = update_available.clone();
+        Callback::from(move |_| update_available.set(false))
+    };
+
     // region: DATE
     let (iso_year, iso_week) = current_iso_week();
     let current_week = use_state(|| iso_week);
     let current_year = use_state(|| iso_year);
+    let mode = use_state(|| if is_narrow_screen() { DisplayMode::Day } else { DisplayMode::Week });
+    let current_month = use_state(|| month_of_week(iso_year, iso_week));
+    let current_day = use_state(|| {
+        let today = now().date();
+        if matches!(today.weekday(), Weekday::Sat | Weekday::Sun) { step_workday(today, 1) } else { today }
+    });
 
     let on_prev = {
         let current_week = current_week.clone();
         let current_year = current_year.clone();
+        let mode = mode.clone();
+        let current_month = current_month.clone();
+        let current_day = current_day.clone();
         Callback::from(move |_| {
+            match *mode {
+                DisplayMode::Month => {
+                    current_month.set(add_months(*current_month, -1));
+                    return;
+                }
+                DisplayMode::Day => {
+                    let day = step_workday(*current_day, -1);
+                    current_day.set(day);
+                    current_week.set(day.iso_week().week() as i32);
+                    current_year.set(day.iso_week().year());
+                    return;
+                }
+                DisplayMode::Week | DisplayMode::Semester => {}
+            }
+
             let mut week = *current_week - 1;
             let mut year = *current_year;
 
@@ -51,7 +151,25 @@ pub fn app() -> Html {
     let on_next = {
         let current_week = current_week.clone();
         let current_year = current_year.clone();
+        let mode = mode.clone();
+        let current_month = current_month.clone();
+        let current_day = current_day.clone();
         Callback::from(move |_| {
+            match *mode {
+                DisplayMode::Month => {
+                    current_month.set(add_months(*current_month, 1));
+                    return;
+                }
+                DisplayMode::Day => {
+                    let day = step_workday(*current_day, 1);
+                    current_day.set(day);
+                    current_week.set(day.iso_week().week() as i32);
+                    current_year.set(day.iso_week().year());
+                    return;
+                }
+                DisplayMode::Week | DisplayMode::Semester => {}
+            }
+
             let mut week = *current_week + 1;
             let mut year = *current_year;
 
@@ -69,40 +187,112 @@ pub fn app() -> Html {
             current_year.set(year);
         })
     };
+
+    let on_mode_change = {
+        let mode = mode.clone();
+        let current_month = current_month.clone();
+        let current_week = current_week.clone();
+        let current_year = current_year.clone();
+        let current_day = current_day.clone();
+        Callback::from(move |new_mode: DisplayMode| {
+            match new_mode {
+                DisplayMode::Month => current_month.set(month_of_week(*current_year, *current_week)),
+                DisplayMode::Day => {
+                    // stay on the shown week, today if it is in it
+                    let today = now().date();
+                    let iso = today.iso_week();
+                    if iso.year() == *current_year && iso.week() as i32 == *current_week && !matches!(today.weekday(), Weekday::Sat | Weekday::Sun) {
+                        current_day.set(today);
+                    } else if let Some(monday) = NaiveDate::from_isoywd_opt(*current_year, *current_week as u32, Weekday::Mon) {
+                        current_day.set(monday);
+                    }
+                }
+                DisplayMode::Week | DisplayMode::Semester => {}
+            }
+            mode.set(new_mode);
+        })
+    };
+
+    // Month and semester cells open the week of that day
+    let on_day_select = {
+        let mode = mode.clone();
+        let current_week = current_week.clone();
+        let current_year = current_year.clone();
+        Callback::from(move |date: NaiveDate| {
+            let iso = date.iso_week();
+            current_week.set(iso.week() as i32);
+            current_year.set(iso.year());
+            mode.set(DisplayMode::Week);
+        })
+    };
     // endregion
 
 
     let db = FrontendDatabase::load();
 
-    // Options for dropdown
-    let mut main_program_options: Vec<(MainProgramId, String)> = db.main_programs
-        .iter()
-        .map(|(id, program)| (*id, program.name.clone()))
-        .collect();
-    main_program_options.sort_by_key(|(id, _)| id.0);
+    // Options for dropdowns
+    let mut main_programs: Vec<MainProgram> = db.main_programs.values().cloned().collect();
+    main_programs.sort_by_key(|mp| (mp.level, mp.year, mp.id.0));
+    let mut programs: Vec<Program> = db.programs.values().cloned().collect();
+    programs.sort_by_key(|p| p.id);
 
     let mut semesters_options: Vec<(Semester, String)> = db.semesters
         .iter()
-        .map(|semester| (*semester, format!("{} semester", semester.0).to_string()))
+        .map(|semester| (*semester, lang.semester(semester.0)))
         .collect();
     semesters_options.sort_by_key(|(id, _)| id.0);
 
 
+    // Last selection, kept in localStorage
+    let saved = load_selection();
+
     // Make subject visibility map
     let mut subjects: Vec<Subject> = db.subjects.values().cloned().collect();
     subjects.sort_by_key(|s| s.id.0);
-    let initial_map: SubjectsVisibilityMap = subjects
+    let mut initial_map: SubjectsVisibilityMap = subjects
         .iter()
         .map(|s| (s.id, SubjectVisibility::new(s)))
         .collect();
+    if let Some(saved) = &saved {
+        saved.apply(&mut initial_map);
+    }
     let visibility_map = use_state(|| initial_map);
+
+    // My group, picked in subjects when they are switched on
+    let profile = use_state(Profile::load);
+    let group_options = |groups: fn(&Subject) -> &BTreeSet<u32>| -> Vec<u32> {
+        subjects.iter().flat_map(|s| groups(s).iter().copied()).collect::<BTreeSet<_>>().into_iter().collect()
+    };
+    let tutorial_group_options = group_options(|s| &s.tutorial_groups);
+    let lab_group_options = group_options(|s| &s.lab_groups);
     drop(subjects);
+    let on_profile_change = {
+        let profile = profile.clone();
+        let visibility_map = visibility_map.clone();
+        Callback::from(move |changed: Profile| {
+            changed.save();
+            visibility_map.set(apply_profile((*visibility_map).clone(), &changed));
+            profile.set(changed);
+        })
+    };
+
+    // Hidden and swapped single sessions
+    let overrides = use_state(Overrides::load);
+    let on_remove_override = {
+        let overrides = overrides.clone();
+        Callback::from(move |item: OverrideItem| {
+            let changed = (*overrides).clone().without(&item);
+            changed.save();
+            overrides.set(changed);
+        })
+    };
 
     // Toggle callbacks
     let on_toggle_subject = {
         let visibility_map = visibility_map.clone();
+        let profile = profile.clone();
         Callback::from(move |subject_id: SubjectId| {
-            visibility_map.set(toggle_subject((*visibility_map).clone(), subject_id));
+            visibility_map.set(toggle_subject((*visibility_map).clone(), subject_id, &profile));
         })
     };
     let on_toggle_subject_group = {
@@ -113,7 +303,7 @@ pub fn app() -> Html {
     };
     
     // Main program select
-    let selected_main_program = use_state(|| MainProgramId(0));
+    let selected_main_program = use_state(|| saved.as_ref().map(|s| s.main_program).unwrap_or(MainProgramId(0)));
     let on_main_program_change = {
         let selected_main_program = selected_main_program.clone();
         Callback::from(move |program_id: MainProgramId| {
@@ -121,8 +311,15 @@ pub fn app() -> Html {
         })
     };
 
+    // Track select, by abbr so it carries over to other years
+    let selected_track = use_state(|| saved.as_ref().and_then(|s| s.track.clone()));
+    let on_track_change = {
+        let selected_track = selected_track.clone();
+        Callback::from(move |track: Option<String>| selected_track.set(track))
+    };
+
     // Semester select
-    let selected_semester = use_state(|| Semester(1));
+    let selected_semester = use_state(|| saved.as_ref().map(|s| s.semester).unwrap_or(Semester(1)));
     let on_semester_change = {
         let selected_semester = selected_semester.clone();
         Callback::from(move |semester_id: Semester| {
@@ -130,91 +327,559 @@ pub fn app() -> Html {
         })
     };
 
-    // Valid subjects and subject options
-    let mut subjects = db.subjects_for_main_program_and_semester(&selected_main_program, *selected_semester);
-    subjects.sort_by_key(|s| s.id.0);
-    let mut subject_options: Vec<(SubjectId, String)> = subjects
+    use_effect_with(
+        (*selected_main_program, (*selected_track).clone(), *selected_semester, (*visibility_map).clone()),
+        |(main_program, track, semester, map)| save_selection(&SavedSelection::new(*main_program, track.clone(), *semester, map)),
+    );
+
+    // Personal events, kept in localStorage
+    let personal_events = use_state(load_personal_events);
+    let on_add_personal_event = {
+        let personal_events = personal_events.clone();
+        Callback::from(move |event: PersonalEvent| {
+            let mut events = (*personal_events).clone();
+            events.push(event);
+            save_personal_events(&events);
+            personal_events.set(events);
+        })
+    };
+    let on_remove_personal_event = {
+        let personal_events = personal_events.clone();
+        Callback::from(move |id: u32| {
+            let mut events = (*personal_events).clone();
+            events.retain(|e| e.id != id);
+            save_personal_events(&events);
+            personal_events.set(events);
+        })
+    };
+
+    // CSV of the whole semester for visible subjects and groups, with my overrides
+    let on_export_csv = {
+        let db = db.clone();
+        let visibility_map = visibility_map.clone();
+        let overrides = overrides.clone();
+        let selected_semester = selected_semester.clone();
+        Callback::from(move |_| {
+            let basket = basket(&visibility_map);
+            let mut entries = db.query()
+                .subjects(&basket)
+                .semester(*selected_semester)
+                .run();
+            entries.retain(|e| is_shown(&visibility_map, &overrides, e));
+            entries.sort_by_key(|e| (e.start_datetime(), e.subject_id));
+
+            let csv = CsvWriter::new().write(&db, &entries);
+            download_text(&format!("timetable-semester-{}.csv", selected_semester.0), "text/csv", &csv);
+        })
+    };
+
+    // ICS of the whole semester for visible subjects and groups, plus personal events in the same date range
+    let on_export_ics = {
+        let db = db.clone();
+        let visibility_map = visibility_map.clone();
+        let overrides = overrides.clone();
+        let selected_semester = selected_semester.clone();
+        let personal_events = personal_events.clone();
+        Callback::from(move |_| {
+            let basket = basket(&visibility_map);
+            let mut entries = db.query()
+                .subjects(&basket)
+                .semester(*selected_semester)
+                .run();
+            entries.retain(|e| is_shown(&visibility_map, &overrides, e));
+
+            let mut events: Vec<Event> = entries.iter().map(|e| Event::from_entry(&db, e)).collect();
+            let first = entries.iter().map(|e| e.date).min();
+            let last = entries.iter().map(|e| e.date).max();
+            if let (Some(first), Some(last)) = (first, last) {
+                for personal in personal_events.iter() {
+                    events.extend(personal.dates_between(first, last).into_iter().map(|date| personal.to_event(date)));
+                }
+            }
+            events.sort_by_key(|e| e.start);
+
+            let calendar = ics::calendar(&format!("Timetable - semester {}", selected_semester.0), &events);
+            download_text(&format!("timetable-semester-{}.ics", selected_semester.0), "text/calendar", &calendar);
+        })
+    };
+
+    // Program, teacher or room timetable
+    let view = use_state(|| View::Program);
+    let selected_entry = use_state(|| None::<EntryId>);
+    let on_entry_click = {
+        let selected_entry = selected_entry.clone();
+        Callback::from(move |id: EntryId| selected_entry.set(Some(id)))
+    };
+    let on_close_detail = {
+        let selected_entry = selected_entry.clone();
+        Callback::from(move |_| selected_entry.set(None))
+    };
+    let on_open_teacher = {
+        let view = view.clone();
+        let selected_entry = selected_entry.clone();
+        Callback::from(move |id: TeacherId| {
+            selected_entry.set(None);
+            view.set(View::Teacher(id));
+        })
+    };
+    let on_open_classroom = {
+        let view = view.clone();
+        let selected_entry = selected_entry.clone();
+        Callback::from(move |id: ClassroomId| {
+            selected_entry.set(None);
+            view.set(View::Classroom(id));
+        })
+    };
+    let on_back_to_program = {
+        let view = view.clone();
+        Callback::from(move |_| view.set(View::Program))
+    };
+
+    // Search
+    let search_query = use_state(String::new);
+    let search_results = db.search(&search_query, 10);
+    let on_search_input = {
+        let search_query = search_query.clone();
+        Callback::from(move |query: String| search_query.set(query))
+    };
+    let on_search_select = {
+        let search_query = search_query.clone();
+        let view = view.clone();
+        let visibility_map = visibility_map.clone();
+        let profile = profile.clone();
+        Callback::from(move |hit: SearchHit| {
+            search_query.set(String::new());
+            match hit {
+                SearchHit::Subject(id) => {
+                    // add to my subjects, whatever program it belongs to
+                    let mut map = (*visibility_map).clone();
+                    if let Some(v) = map.get_mut(&id).filter(|v| !v.can_show) {
+                        v.set_subject_visibility(true);
+                        v.apply_profile(&profile);
+                    }
+                    visibility_map.set(map);
+                    view.set(View::Program);
+                }
+                SearchHit::Teacher(id) => view.set(View::Teacher(id)),
+                SearchHit::Classroom(id) => view.set(View::Classroom(id)),
+            }
+        })
+    };
+
+    // Subjects of the picked program to browse, my subjects from any program for groups and the timetable
+    let mut program_subjects = db.subjects_for_main_program_and_semester(&selected_main_program, *selected_semester);
+    program_subjects.sort_by_key(|s| s.id.0);
+    // a picked track narrows them to the ones its program lists
+    if let Some(track) = &*selected_track {
+        let track_programs: BTreeSet<ProgramId> = programs
+            .iter()
+            .filter(|p| p.main_program_id == *selected_main_program && p.track.as_ref().is_some_and(|t| &t.abbr == track))
+            .map(|p| p.id)
+            .collect();
+        if !track_programs.is_empty() {
+            program_subjects.retain(|s| {
+                s.offerings.iter().any(|o| {
+                    o.main_program_id == *selected_main_program
+                        && o.semester == *selected_semester
+                        && !o.program_ids.is_disjoint(&track_programs)
+                })
+            });
+        }
+    }
+    let subject_options: Vec<(SubjectId, String)> = program_subjects
         .iter()
         .map(|s| (s.id, s.abbr.clone()))
         .collect();
-    subject_options.sort_by_key(|(subject_id, _)| *subject_id);
-    
-    let colors = create_multiple_colors(subject_options.len());
+    let mut subjects: Vec<Subject> = basket(&visibility_map)
+        .iter()
+        .filter_map(|id| db.subjects.get(id).cloned())
+        .collect();
+    subjects.sort_by_key(|s| (s.offerings.first().map(|o| (o.semester.0, o.main_program_id.0)), s.id.0));
+    // where my subjects from other programs come from
+    let subject_programs: HashMap<SubjectId, String> = subjects
+        .iter()
+        .filter(|s| !s.main_program_ids().contains(&selected_main_program))
+        .map(|s| {
+            let names: Vec<&str> = s.main_program_ids()
+                .iter()
+                .filter_map(|id| db.main_programs.get(id))
+                .map(|p| p.name.as_str())
+                .collect();
+            (s.id, names.join(", "))
+        })
+        .collect();
+
+    // Colors from the palette unless picked by the user
+    let colors = {
+        let basket = basket(&visibility_map);
+        use_state(move || SubjectColors::load().with_basket(&basket))
+    };
+    {
+        let colors = colors.clone();
+        use_effect_with(basket(&visibility_map), move |basket| {
+            let changed = (*colors).clone().with_basket(basket);
+            if changed != *colors {
+                colors.set(changed);
+            }
+        });
+    }
+    // the picker and my subjects, plus whatever the shown teacher or classroom has
+    let view_entries = match &*view {
+        View::Program => vec![],
+        View::Teacher(id) => db.query().teacher(id).run(),
+        View::Classroom(id) => db.query().classroom(id).run(),
+    };
     let subject_colors: HashMap<SubjectId, Color> = subject_options
         .iter()
         .map(|(subject_id, _name)| *subject_id)
-        .zip(colors.into_iter())
+        .chain(subjects.iter().map(|s| s.id))
+        .chain(view_entries.iter().map(|e| e.subject_id))
+        .map(|subject_id| (subject_id, colors.get(subject_id)))
         .collect();
+    let on_palette_change = {
+        let colors = colors.clone();
+        Callback::from(move |palette: Palette| colors.set((*colors).clone().with_palette(palette)))
+    };
+    let on_color_change = {
+        let colors = colors.clone();
+        Callback::from(move |(id, color): (SubjectId, Option<Color>)| colors.set((*colors).clone().with_override(id, color)))
+    };
 
-    
-    // Entries
-    let entries = db.query()
-        .main_program(&selected_main_program)
-        .semester(*selected_semester)
-        .week(*current_week)
-        .run()
-        .into_iter()
-        .cloned()
-        .collect::<Vec<_>>();
-
-    let entry_props = entries
-        .iter()
-        .map(|e| entry_to_props(&db, e))
-        .collect::<Vec<_>>();
+    // PNG and SVG of the shown week, PDF with a page per week of the semester
+    let on_export = {
+        let db = db.clone();
+        let lang = lang.clone();
+        let view = view.clone();
+        let visibility_map = visibility_map.clone();
+        let overrides = overrides.clone();
+        let selected_semester = selected_semester.clone();
+        let personal_events = personal_events.clone();
+        let subject_colors = subject_colors.clone();
+        let current_week = current_week.clone();
+        let current_year = current_year.clone();
+        Callback::from(move |format: ExportFormat| {
+            let source = EntrySource {
+                db: &db,
+                view: *view,
+                visibility_map: &visibility_map,
+                overrides: &overrides,
+                colors: &subject_colors,
+                personal_events: &personal_events,
+            };
+            let title = match *view {
+                View::Program => {
+                    // programs of my subjects, e.g. "MAG-1, MAG-2"
+                    let mut names: Vec<String> = basket(&visibility_map)
+                        .iter()
+                        .filter_map(|id| db.subjects.get(id))
+                        .flat_map(|s| s.offerings.iter().filter(|o| o.semester == *selected_semester))
+                        .filter_map(|o| db.main_programs.get(&o.main_program_id))
+                        .map(|p| p.name.clone())
+                        .collect();
+                    names.sort();
+                    names.dedup();
+                    Some(names.join(", "))
+                }
+                View::Teacher(id) => db.teachers.get(&id).map(|t| t.name.clone()),
+                View::Classroom(id) => db.classrooms.get(&id).map(|c| c.full_name.clone()),
+            }.unwrap_or_default();
+            let page = |monday| WeekPage::new(&source, *lang, &title, *selected_semester, monday, &db.work_free_days);
 
-    let entry_props = filter_entries(entry_props, &visibility_map);
-    let mut entry_props = arrange_columns(entry_props);
+            match format {
+                ExportFormat::Png | ExportFormat::Svg => {
+                    let monday = NaiveDate::from_isoywd_opt(*current_year, *current_week as u32, Weekday::Mon).unwrap();
+                    let svg = export::svg::render(&page(monday), *lang);
+                    let filename = format!("timetable-{}-{:02}", *current_year, *current_week);
+                    if format == ExportFormat::Png {
+                        export::png::download_png(&svg, &format!("{filename}.png"));
+                    } else {
+                        download_text(&format!("{filename}.svg"), "image/svg+xml", &svg);
+                    }
+                }
+                ExportFormat::Pdf => {
+                    let pages: Vec<WeekPage> = semester_mondays(&selected_semester).into_iter().map(page).collect();
+                    let pdf = export::pdf::render(&pages, *lang);
+                    download_bytes(&format!("timetable-semester-{}.pdf", selected_semester.0), "application/pdf", &pdf);
+                }
+            }
+        })
+    };
 
-    for entry in entry_props.iter_mut() {
-        entry.color = subject_colors.get(&entry.subject_id).unwrap().clone();
+    
+    // Entries of the shown week, or of the month or semester
+    let range = match *mode {
+        DisplayMode::Day => Some((*current_day, *current_day)),
+        DisplayMode::Week => None,
+        DisplayMode::Month => {
+            let mondays = month_mondays(*current_month);
+            Some((mondays[0], *mondays.last().unwrap() + Duration::days(6)))
+        }
+        DisplayMode::Semester => {
+            let mondays = semester_mondays(&selected_semester);
+            Some((mondays[0], *mondays.last().unwrap() + Duration::days(6)))
+        }
+    };
+    let source = EntrySource {
+        db: &db,
+        view: *view,
+        visibility_map: &visibility_map,
+        overrides: &overrides,
+        colors: &subject_colors,
+        personal_events: &personal_events,
+    };
+    let entry_props = match range {
+        None => source.week(*current_year, *current_week),
+        Some((from, to)) => source.between(from, to),
+    };
+    let entry_props = match *mode {
+        DisplayMode::Week => mark_clashes(arrange_columns(entry_props)),
+        DisplayMode::Day => mark_clashes(entry_props),
+        DisplayMode::Month | DisplayMode::Semester => entry_props,
+    };
+
+    // Printing: the shown week plus the following ones, each on its own page
+    let print_weeks = use_state(|| 1u32);
+    let on_print_weeks_change = {
+        let print_weeks = print_weeks.clone();
+        Callback::from(move |weeks: u32| print_weeks.set(weeks))
+    };
+    let printing = use_state(|| false);
+    let on_print = {
+        let printing = printing.clone();
+        let mode = mode.clone();
+        Callback::from(move |_| {
+            mode.set(DisplayMode::Week);
+            printing.set(true);
+        })
+    };
+    {
+        let printing = printing.clone();
+        use_effect_with(*printing, move |is_printing| {
+            if *is_printing {
+                if let Some(window) = web_sys::window() {
+                    let _ = window.print();
+                }
+                printing.set(false);
+            }
+        });
     }
+    let extra_print_weeks: Vec<(i32, i32, Vec<EntryBoxProps>, Vec<NaiveDate>)> = if *printing && *mode == DisplayMode::Week {
+        let monday = NaiveDate::from_isoywd_opt(*current_year, *current_week as u32, Weekday::Mon).unwrap();
+        (1..*print_weeks)
+            .map(|i| {
+                let iso = (monday + Duration::weeks(i as i64)).iso_week();
+                let (year, week) = (iso.year(), iso.week() as i32);
+                let work_free_days = db.work_free_days
+                    .iter()
+                    .filter(|d| d.iso_week() == iso)
+                    .copied()
+                    .collect();
+                (year, week, mark_clashes(arrange_columns(source.week(year, week))), work_free_days)
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+
+    let view_label = match *view {
+        View::Program => None,
+        View::Teacher(id) => Some(db.teachers.get(&id).map(|t| t.name.clone()).unwrap_or_default()),
+        View::Classroom(id) => Some(db.classrooms.get(&id).map(|c| c.full_name.clone()).unwrap_or_default()),
+    };
+
+    let selected = selected_entry.and_then(|id| db.entry(&id)).cloned();
+    let entry_detail = selected.as_ref().map(|entry| EntryDetail::new(&db, entry));
+    // sessions of my subjects can be hidden or swapped with another group in the program view
+    let overridable = selected
+        .as_ref()
+        .filter(|entry| *view == View::Program && basket(&visibility_map).contains(&entry.subject_id));
+    let on_override = overridable.cloned().map(|entry| {
+        let overrides = overrides.clone();
+        let selected_entry = selected_entry.clone();
+        Callback::from(move |action: OverrideAction| {
+            let changed = (*overrides).clone().with(&entry, action);
+            changed.save();
+            overrides.set(changed);
+            selected_entry.set(None);
+        })
+    });
+    let overridden = overridable.is_some_and(|entry| overrides.is_overridden(&entry.id, &Series::of(entry)));
+    let entry_alternatives: Vec<_> = overridable
+        .map(|entry| {
+            alternatives(&db, &visibility_map, &overrides, entry)
+                .into_iter()
+                .map(|e| (e.id, e.entry_type.clone(), e.date, e.start_time))
+                .collect()
+        })
+        .unwrap_or_default();
+    let override_items = override_labels(&db, *lang, &overrides);
 
-    let all_wfd: Vec<NaiveDate> = db.work_free_days;
-    let wfd: Vec<NaiveDate> = all_wfd
-        .into_iter()
-        .filter(|d| {
-            let iso = d.iso_week();
-            iso.year() == *current_year && iso.week() == *current_week as u32
+    let wfd: Vec<NaiveDate> = db.work_free_days
+        .iter()
+        .copied()
+        .filter(|d| match range {
+            None => {
+                let iso = d.iso_week();
+                iso.year() == *current_year && iso.week() == *current_week as u32
+            }
+            Some((from, to)) => (from..=to).contains(d),
         })
         .collect();
 
 
     html! {
-        <>
+        <ContextProvider<Lang> context={*lang}>
+            if *update_available {
+                <UpdateBanner on_dismiss={on_dismiss_update} />
+            }
             <Header
-                main_program_options={main_program_options.clone()}
+                main_programs={main_programs.clone()}
+                programs={programs.clone()}
                 selected_main_program={*selected_main_program}
                 on_main_program_change={on_main_program_change}
+                selected_track={(*selected_track).clone()}
+                on_track_change={on_track_change}
 
                 semester_options={semesters_options.clone()}
                 selected_semester={*selected_semester}
                 on_semester_change={on_semester_change}
+
+                on_export={on_export}
+                on_export_csv={on_export_csv}
+                on_export_ics={on_export_ics}
+
+                search_query={(*search_query).clone()}
+                search_results={search_results}
+                on_search_input={on_search_input}
+                on_search_select={on_search_select}
+
+                on_lang_change={on_lang_change}
+
+                palette={colors.palette}
+                on_palette_change={on_palette_change}
+
+                theme={*theme}
+                on_theme_change={on_theme_change}
+
+                print_weeks={*print_weeks}
+                on_print_weeks_change={on_print_weeks_change}
+                on_print={on_print}
             />
-            <SubjectAbbrContainer
-                subjects_visibility={(*visibility_map).clone()} 
-                subjects={subject_options}
-                subject_colors={subject_colors.clone()}
-                on_toggle={on_toggle_subject.clone()}
-            />
-            <SubjectContainer
-                subjects={subjects}
-                subjects_visibility={(*visibility_map).clone()}
-                subject_colors={subject_colors}
-                on_toggle_subject={on_toggle_subject}
-                on_toggle_group={on_toggle_subject_group}
-            />
+            if view_label.is_none() {
+                <PrintLegend
+                    subjects={subjects.clone()}
+                    subjects_visibility={(*visibility_map).clone()}
+                    subject_colors={subject_colors.clone()}
+                />
+            }
+            if let Some(label) = view_label {
+                <ViewBanner label={label} on_back={on_back_to_program} />
+            } else {
+                <SubjectAbbrContainer
+                    subjects_visibility={(*visibility_map).clone()} 
+                    subjects={subject_options}
+                    subject_colors={subject_colors.clone()}
+                    on_toggle={on_toggle_subject.clone()}
+                />
+                <MyGroup
+                    tutorial_groups={tutorial_group_options}
+                    lab_groups={lab_group_options}
+                    profile={*profile}
+                    on_change={on_profile_change}
+                />
+                if !override_items.is_empty() {
+                    <OverridesList items={override_items} on_remove={on_remove_override} />
+                }
+                <SubjectContainer
+                    subjects={subjects}
+                    subject_programs={subject_programs}
+                    subjects_visibility={(*visibility_map).clone()}
+                    subject_colors={subject_colors}
+                    on_toggle_subject={on_toggle_subject}
+                    on_toggle_group={on_toggle_subject_group}
+                    on_color_change={on_color_change}
+                />
+                <PersonalEvents
+                    events={(*personal_events).clone()}
+                    on_add={on_add_personal_event}
+                    on_remove={on_remove_personal_event}
+                />
+            }
             <Calendar
                 current_week = {*current_week}
                 year = {*current_year}
-                on_previous = {on_prev}
-                on_next = {on_next}
-            />
-            <Timetable
-                current_week = {*current_week}
-                year = {*current_year}
-                entries={entry_props}
-                work_free_days={wfd}
+                on_previous = {on_prev.clone()}
+                on_next = {on_next.clone()}
+                mode = {*mode}
+                day = {*current_day}
+                month = {*current_month}
+                semester = {*selected_semester}
+                on_mode_change = {on_mode_change}
             />
-        </>
+            {
+                match *mode {
+                    DisplayMode::Day => html! {
+                        <DayView
+                            date={*current_day}
+                            is_work_free={wfd.contains(&current_day)}
+                            entries={entry_props}
+                            on_previous={on_prev.clone()}
+                            on_next={on_next.clone()}
+                            on_entry_click={on_entry_click}
+                        />
+                    },
+                    DisplayMode::Week => html! {
+                        <>
+                            <Timetable
+                                current_week = {*current_week}
+                                year = {*current_year}
+                                entries={entry_props}
+                                work_free_days={wfd}
+                                on_entry_click={on_entry_click.clone()}
+                            />
+                            { for extra_print_weeks.into_iter().map(|(year, week, entries, work_free_days)| html! {
+                                <div class="print-only">
+                                    <Timetable
+                                        current_week={week}
+                                        year={year}
+                                        entries={entries}
+                                        work_free_days={work_free_days}
+                                        on_entry_click={on_entry_click.clone()}
+                                        print_copy=true
+                                    />
+                                </div>
+                            }) }
+                        </>
+                    },
+                    DisplayMode::Month => html! {
+                        <MonthView
+                            month={*current_month}
+                            entries={entry_props}
+                            work_free_days={wfd}
+                            on_day_select={on_day_select}
+                        />
+                    },
+                    DisplayMode::Semester => html! {
+                        <SemesterView
+                            semester={*selected_semester}
+                            entries={entry_props}
+                            work_free_days={wfd}
+                            on_day_select={on_day_select}
+                        />
+                    },
+                }
+            }
+            if let Some(detail) = entry_detail {
+                <EntryDetailPanel
+                    detail={detail}
+                    on_close={on_close_detail}
+                    on_open_teacher={on_open_teacher}
+                    on_open_classroom={on_open_classroom}
+                    on_override={on_override}
+                    overridden={overridden}
+                    alternatives={entry_alternatives}
+                />
+            }
+        </ContextProvider<Lang>>
     }
 }
@@ -1,5 +1,10 @@
 use yew::prelude::*;
 use chrono::{Datelike, Duration, NaiveDate};
+use shared::definitions::Semester;
+use shared::time::get_semester_data;
+
+use crate::i18n::{use_lang, Text};
+use crate::logic::view::DisplayMode;
 
 fn monday_of_week(year: i32, week: i32) -> NaiveDate {
     // ISO week: week starts on Monday
@@ -8,17 +13,22 @@ fn monday_of_week(year: i32, week: i32) -> NaiveDate {
     week1_monday + Duration::weeks((week - 1) as i64)
 }
 
-
 #[derive(Properties, PartialEq)]
 pub struct CalendarProps {
     pub current_week: i32,
     pub year: i32,
     pub on_previous: Callback<()>,
     pub on_next: Callback<()>,
+    pub mode: DisplayMode,
+    pub day: NaiveDate,   // shown in day mode
+    pub month: NaiveDate, // first day of the month shown in month mode
+    pub semester: Semester,
+    pub on_mode_change: Callback<DisplayMode>,
 }
 
 #[function_component(Calendar)]
 pub fn calendar(props: &CalendarProps) -> Html {
+    let lang = use_lang();
     let on_prev_click = {
         let cb = props.on_previous.clone();
         Callback::from(move |_: web_sys::MouseEvent| cb.emit(()))
@@ -28,16 +38,48 @@ pub fn calendar(props: &CalendarProps) -> Html {
         Callback::from(move |_: web_sys::MouseEvent| cb.emit(()))
     };
 
-    let monday = monday_of_week(props.year, props.current_week);
-    let friday = monday + chrono::Duration::days(4);
+    let label = match props.mode {
+        DisplayMode::Day => format!("{} {}", lang.weekday(props.day.weekday()), lang.date(props.day)),
+        DisplayMode::Week => {
+            let monday = monday_of_week(props.year, props.current_week);
+            let friday = monday + chrono::Duration::days(4);
+            format!("{} - {}", lang.date(monday), lang.date(friday))
+        }
+        DisplayMode::Month => lang.month_year(props.month),
+        DisplayMode::Semester => {
+            let (start, end) = get_semester_data(&props.semester);
+            format!("{}: {} - {}", lang.semester(props.semester.0), lang.date(start), lang.date(end))
+        }
+    };
+    // the semester view always shows the whole selected semester
+    let can_navigate = props.mode != DisplayMode::Semester;
+
+    let modes = [
+        (Text::Day, DisplayMode::Day),
+        (Text::Week, DisplayMode::Week),
+        (Text::Month, DisplayMode::Month),
+        (Text::Semester, DisplayMode::Semester),
+    ];
 
     html! {
         <div id="calendar-container">
-            <button id="calendar-previous-button" onclick={on_prev_click}>{ "<" }</button>
-            <div id="current-week">
-                { format!("{} - {}", monday.format("%d.%m.%Y"), friday.format("%d.%m.%Y")) }
+            <div class="display-modes">
+                { for modes.iter().map(|(text, mode)| {
+                    let mode = *mode;
+                    let callback = props.on_mode_change.clone();
+                    html! {
+                        <button
+                            class={classes!("mode-button", (mode == props.mode).then_some("active-button"))}
+                            onclick={Callback::from(move |_| callback.emit(mode))}
+                        >
+                            { lang.t(*text) }
+                        </button>
+                    }
+                }) }
             </div>
-            <button id="calendar-next-button" onclick={on_next_click}>{ ">" }</button>
+            <button id="calendar-previous-button" onclick={on_prev_click} disabled={!can_navigate}>{ "<" }</button>
+            <div id="current-week">{ label }</div>
+            <button id="calendar-next-button" onclick={on_next_click} disabled={!can_navigate}>{ ">" }</button>
         </div>
     }
 }
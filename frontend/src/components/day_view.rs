@@ -0,0 +1,159 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use gloo::events::EventListener;
+use gloo::timers::callback::Interval;
+use shared::definitions::EntryId;
+use wasm_bindgen::JsCast;
+use web_sys::KeyboardEvent;
+use yew::prelude::*;
+
+use crate::components::entry_box::EntryBoxProps;
+use crate::i18n::{use_lang, Lang, Text};
+use crate::logic::date::now;
+
+// Horizontal distance in px that counts as a swipe
+const SWIPE_DISTANCE: i32 = 60;
+
+#[derive(Properties, PartialEq)]
+pub struct DayViewProps {
+    pub date: NaiveDate,
+    pub entries: Vec<EntryBoxProps>,
+    pub is_work_free: bool,
+    pub on_previous: Callback<()>,
+    pub on_next: Callback<()>,
+    pub on_entry_click: Callback<EntryId>,
+}
+
+/// Agenda of one day for narrow screens
+#[function_component(DayView)]
+pub fn day_view(props: &DayViewProps) -> Html {
+    let lang = use_lang();
+    // re-render every minute so the now line and the banner move
+    let current_time = use_state(now);
+    {
+        let current_time = current_time.clone();
+        use_effect_with((), move |_| {
+            let interval = Interval::new(60_000, move || current_time.set(now()));
+            move || drop(interval)
+        });
+    }
+
+    // left/right arrows change the day
+    {
+        let on_previous = props.on_previous.clone();
+        let on_next = props.on_next.clone();
+        use_effect_with((), move |_| {
+            let document = web_sys::window().unwrap().document().unwrap();
+            let listener = EventListener::new(&document, "keydown", move |e| {
+                let Some(e) = e.dyn_ref::<KeyboardEvent>() else { return };
+                match e.key().as_str() {
+                    "ArrowLeft" => on_previous.emit(()),
+                    "ArrowRight" => on_next.emit(()),
+                    _ => {}
+                }
+            });
+            move || drop(listener)
+        });
+    }
+
+    let touch_start = use_mut_ref(|| None::<i32>);
+    let on_touch_start = {
+        let touch_start = touch_start.clone();
+        Callback::from(move |e: TouchEvent| {
+            *touch_start.borrow_mut() = e.touches().get(0).map(|t| t.client_x());
+        })
+    };
+    let on_touch_end = {
+        let on_previous = props.on_previous.clone();
+        let on_next = props.on_next.clone();
+        Callback::from(move |e: TouchEvent| {
+            let Some(start) = touch_start.borrow_mut().take() else { return };
+            let Some(end) = e.changed_touches().get(0).map(|t| t.client_x()) else { return };
+            if end - start > SWIPE_DISTANCE {
+                on_previous.emit(());
+            } else if start - end > SWIPE_DISTANCE {
+                on_next.emit(());
+            }
+        })
+    };
+
+    let mut entries: Vec<&EntryBoxProps> = props.entries.iter().collect();
+    entries.sort_by_key(|e| (e.start_time, e.end_time));
+
+    let now = *current_time;
+    let is_today = now.date() == props.date;
+    let banner = if is_today { banner_text(lang, &entries, now) } else { None };
+
+    // index of the first entry that starts after now
+    let now_index = is_today.then(|| entries.iter().position(|e| e.start_time > now.time()).unwrap_or(entries.len()));
+
+    let mut items: Vec<Html> = vec![];
+    for (i, entry) in entries.iter().enumerate() {
+        if now_index == Some(i) {
+            items.push(now_line(now));
+        }
+        let onclick = entry.entry_id.map(|id| {
+            let on_entry_click = props.on_entry_click.clone();
+            Callback::from(move |_: MouseEvent| on_entry_click.emit(id))
+        });
+        let is_current = is_today && entry.start_time <= now.time() && now.time() < entry.end_time;
+        items.push(html! {
+            <div
+                class={classes!("agenda-entry", is_current.then_some("agenda-current"), entry.clash.then_some("clash"))}
+                style={format!("border-left-color: {}", entry.color.css())}
+                {onclick}
+            >
+                <div class="agenda-time">
+                    { format!("{} - {}", entry.start_time.format("%H:%M"), entry.end_time.format("%H:%M")) }
+                </div>
+                <div class="agenda-title">
+                    { &entry.subject_name }
+                    if !entry.personal {
+                        <span class="agenda-type">{ format!(" {}", lang.entry_type(&entry.entry_type)) }</span>
+                    }
+                </div>
+                if !entry.classroom.is_empty() {
+                    <div class="agenda-room">{ &entry.classroom }</div>
+                }
+                if !entry.teachers.is_empty() {
+                    <div class="agenda-teachers">{ entry.teachers.join(", ") }</div>
+                }
+            </div>
+        });
+    }
+    if now_index == Some(entries.len()) && !entries.is_empty() {
+        items.push(now_line(now));
+    }
+
+    html! {
+        <div id="day-view" ontouchstart={on_touch_start} ontouchend={on_touch_end}>
+            if let Some(text) = banner {
+                <div class="agenda-banner">{ text }</div>
+            }
+            if props.is_work_free {
+                <div class="agenda-empty">{ lang.t(Text::WorkFreeDay) }</div>
+            } else if entries.is_empty() {
+                <div class="agenda-empty">{ lang.t(Text::NoClasses) }</div>
+            }
+            { for items }
+        </div>
+    }
+}
+
+fn now_line(now: NaiveDateTime) -> Html {
+    html! {
+        <div class="agenda-now">
+            <span>{ now.format("%H:%M").to_string() }</span>
+        </div>
+    }
+}
+
+fn banner_text(lang: Lang, entries: &[&EntryBoxProps], now: NaiveDateTime) -> Option<String> {
+    let time = now.time();
+    if let Some(current) = entries.iter().find(|e| e.start_time <= time && time < e.end_time) {
+        return Some(lang.class_ends_in(&current.subject_abbr, (current.end_time - time).num_minutes()));
+    }
+    entries
+        .iter()
+        .find(|e| e.start_time > time)
+        .map(|next| lang.next_class_in(&next.subject_abbr, &next.classroom, (next.start_time - time).num_minutes()))
+}
@@ -1,7 +1,8 @@
 use chrono::{NaiveDate, NaiveTime, Weekday, Datelike};
-use shared::{db::{Database}, definitions::{Entry, EntryType, SubjectId}, time::{DURATION_MAP, START_TIME_MAP}};
+use shared::{db::{Database}, definitions::{Entry, EntryId, EntryType, Minutes, SubjectId}, layout::Slot, time::{duration_to_percent, time_to_percent}};
 use yew::prelude::*;
 
+use crate::i18n::use_lang;
 use crate::utils::Color;
 
 pub fn entry_to_props(db: &Database, entry: &Entry) -> EntryBoxProps {
@@ -30,6 +31,7 @@ pub fn entry_to_props(db: &Database, entry: &Entry) -> EntryBoxProps {
     let day = entry.date.weekday();
 
     EntryBoxProps {
+        entry_id: Some(entry.id),
         subject_id: entry.subject_id,
         subject_name,
         subject_abbr,
@@ -40,15 +42,19 @@ pub fn entry_to_props(db: &Database, entry: &Entry) -> EntryBoxProps {
         date: entry.date,
         start_time: entry.start_time,
         duration: entry.duration,
+        end_time: entry.end_time(),
         offset_x_multiplier: 0.0, // will be changed later
         total_columns: 1, // will be changed later
-        color: Color { r: 0, g: 0, b: 0 } // will be changed later
+        color: Color { r: 0, g: 0, b: 0 }, // will be changed later
+        personal: false,
+        clash: false, // will be changed later
     }
 }
 
 
 #[derive(Properties, PartialEq, Clone)]
 pub struct EntryBoxProps {
+    pub entry_id: Option<EntryId>, // None for personal events
     pub subject_id: SubjectId,
     pub subject_name: String,
     pub subject_abbr: String,
@@ -58,14 +64,32 @@ pub struct EntryBoxProps {
     pub day: Weekday,
     pub date: NaiveDate,
     pub start_time: NaiveTime,
-    pub duration: NaiveTime,
+    pub duration: Minutes,
+    pub end_time: NaiveTime,
     pub offset_x_multiplier: f32,
     pub total_columns: usize,
     pub color: Color,
+    pub personal: bool, // user defined event, not from the timetable
+    pub clash: bool,    // overlaps another visible entry
+}
+
+impl Slot for EntryBoxProps {
+    fn weekday(&self) -> Weekday {
+        self.day
+    }
+
+    fn start_time(&self) -> NaiveTime {
+        self.start_time
+    }
+
+    fn end_time(&self) -> NaiveTime {
+        self.end_time
+    }
 }
 
 #[function_component(EntryBox)]
 pub fn entry_box(props: &EntryBoxProps) -> Html {
+    let lang = use_lang();
     let x_gap = 0.3;
     let y_gap = 0.5;
     let base_width = 20.0;
@@ -85,8 +109,8 @@ pub fn entry_box(props: &EntryBoxProps) -> Html {
     };
     left += props.offset_x_multiplier * column_width + x_gap;
 
-    let top= START_TIME_MAP.get_by_right(&props.start_time).unwrap().trim_end_matches('%').parse::<f32>().unwrap() + y_gap;
-    let height = DURATION_MAP.get_by_right(&props.duration).unwrap().trim_end_matches('%').parse::<f32>().unwrap() - y_gap * 3.0;
+    let top = time_to_percent(props.start_time) + y_gap;
+    let height = duration_to_percent(props.duration) - y_gap * 3.0;
 
     let mut font_size = 12;
     if width < 10.0 { font_size = 10; }
@@ -94,7 +118,9 @@ pub fn entry_box(props: &EntryBoxProps) -> Html {
     if width < 3.0 { font_size = 8; }
     if width < 2.0 { font_size = 7; }
 
-    let entry_class = if props.entry_type == EntryType::Lecture {
+    let entry_class = if props.personal {
+        "personal"
+    } else if props.entry_type == EntryType::Lecture {
         "lecture"
     } else {
         "exercise"
@@ -105,7 +131,7 @@ pub fn entry_box(props: &EntryBoxProps) -> Html {
         props.color.css()
     );
 
-    let font_style = format!("font-size:{font_size}px");
+    let font_style = format!("font-size:{font_size}px; color:{}", props.color.text_color().css());
 
     let subject_text = if props.subject_name.len() < 25 && width > 9.0 {
         &props.subject_name
@@ -113,11 +139,15 @@ pub fn entry_box(props: &EntryBoxProps) -> Html {
         &props.subject_abbr
     };
 
-    let entry_type_str = props.entry_type.to_string();
-    let show_classroom = width >= 9.0;
+    let entry_type_str = if props.personal {
+        format!("{} - {}", props.start_time.format("%H:%M"), props.end_time.format("%H:%M"))
+    } else {
+        lang.entry_type_short(&props.entry_type)
+    };
+    let show_classroom = width >= 9.0 && !props.personal;
 
     html! {
-        <div class={classes!("entry-box", entry_class)} style={style_top_height_left_width}>
+        <div class={classes!("entry-box", entry_class, props.clash.then_some("clash"))} style={style_top_height_left_width}>
             <div class="entry" style={font_style}>
                 <div class="subject-title-type-group">
                     <div class="subject-title">{ subject_text }</div>
@@ -0,0 +1,153 @@
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use shared::db::Database;
+use shared::definitions::{ClassroomId, Entry, EntryId, EntryType, TeacherId};
+use shared::ics::BASE_URL;
+use yew::prelude::*;
+
+use crate::i18n::{use_lang, Text};
+use crate::logic::overrides::OverrideAction;
+
+/// Everything the detail panel shows about one entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryDetail {
+    pub subject_name: String,
+    pub subject_abbr: String,
+    pub subject_url: Option<String>,
+    pub entry_type: EntryType,
+    pub date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub teachers: Vec<(TeacherId, String, String)>, // id, name, url
+    pub classroom: Option<(ClassroomId, String, String)>,
+}
+
+impl EntryDetail {
+    pub fn new(db: &Database, entry: &Entry) -> Self {
+        let subject = db.subjects.get(&entry.subject_id);
+        let mut teachers: Vec<(TeacherId, String, String)> = entry.teacher_ids
+            .iter()
+            .filter_map(|id| db.teachers.get(id))
+            .map(|t| (t.id, t.name.clone(), format!("{BASE_URL}{}", t.url)))
+            .collect();
+        teachers.sort_by(|a, b| a.1.cmp(&b.1));
+
+        EntryDetail {
+            subject_name: subject.map(|s| s.name.clone()).unwrap_or_default(),
+            subject_abbr: subject.map(|s| s.abbr.clone()).unwrap_or_default(),
+            subject_url: subject.map(|s| format!("{BASE_URL}{}", s.url)),
+            entry_type: entry.entry_type.clone(),
+            date: entry.date,
+            start_time: entry.start_time,
+            end_time: entry.end_time(),
+            teachers,
+            classroom: db.classrooms
+                .get(&entry.classroom_id)
+                .map(|c| (c.id, c.full_name.clone(), format!("{BASE_URL}{}", c.url))),
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct EntryDetailProps {
+    pub detail: EntryDetail,
+    pub on_close: Callback<()>,
+    pub on_open_teacher: Callback<TeacherId>,
+    pub on_open_classroom: Callback<ClassroomId>,
+    pub on_override: Option<Callback<OverrideAction>>, // only for my subjects in the program view
+    pub overridden: bool,
+    pub alternatives: Vec<(EntryId, EntryType, NaiveDate, NaiveTime)>, // other groups' sessions that week
+}
+
+#[function_component(EntryDetailPanel)]
+pub fn entry_detail_panel(props: &EntryDetailProps) -> Html {
+    let lang = use_lang();
+    let detail = &props.detail;
+
+    let on_backdrop_click = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_| on_close.emit(()))
+    };
+    // clicks inside the panel must not reach the backdrop
+    let on_panel_click = Callback::from(|e: MouseEvent| e.stop_propagation());
+    let action = |callback: &Callback<OverrideAction>, action: OverrideAction| {
+        let callback = callback.clone();
+        Callback::from(move |_: MouseEvent| callback.emit(action))
+    };
+
+    html! {
+        <div class="entry-detail-backdrop" onclick={on_backdrop_click.clone()}>
+            <div class="entry-detail" onclick={on_panel_click}>
+                <button class="entry-detail-close" onclick={on_backdrop_click} title={lang.t(Text::Close)}>{ "✕" }</button>
+                <div class="entry-detail-title">
+                    if let Some(url) = &detail.subject_url {
+                        <a href={url.clone()} target="_blank">{ &detail.subject_name }</a>
+                    } else {
+                        { &detail.subject_name }
+                    }
+                    { format!(" ({})", detail.subject_abbr) }
+                </div>
+                <div class="entry-detail-row">{ lang.entry_type(&detail.entry_type) }</div>
+                <div class="entry-detail-row">
+                    { format!(
+                        "{} {} {} - {}",
+                        lang.weekday(detail.date.weekday()),
+                        lang.date(detail.date),
+                        detail.start_time.format("%H:%M"),
+                        detail.end_time.format("%H:%M"),
+                    ) }
+                </div>
+                <div class="entry-detail-label">{ lang.t(Text::Teachers) }</div>
+                { for detail.teachers.iter().map(|(id, name, url)| {
+                    let id = *id;
+                    let on_open = props.on_open_teacher.clone();
+                    html! {
+                        <div class="entry-detail-row">
+                            <a href={url.clone()} target="_blank">{ name }</a>
+                            <button onclick={Callback::from(move |_| on_open.emit(id))} title={lang.t(Text::ShowTeacherTimetable)}>{ "📅" }</button>
+                        </div>
+                    }
+                }) }
+                if let Some((id, name, url)) = &detail.classroom {
+                    <div class="entry-detail-label">{ lang.t(Text::Classroom) }</div>
+                    <div class="entry-detail-row">
+                        <a href={url.clone()} target="_blank">{ name }</a>
+                        <button
+                            onclick={
+                                let id = *id;
+                                let on_open = props.on_open_classroom.clone();
+                                Callback::from(move |_| on_open.emit(id))
+                            }
+                            title={lang.t(Text::ShowRoomTimetable)}
+                        >
+                            { "📅" }
+                        </button>
+                    </div>
+                }
+                if let Some(on_override) = &props.on_override {
+                    <div class="entry-detail-overrides">
+                        if props.overridden {
+                            <button onclick={action(on_override, OverrideAction::Reset)}>{ format!("↺ {}", lang.t(Text::ResetOverride)) }</button>
+                        } else {
+                            <button onclick={action(on_override, OverrideAction::Hide)}>{ format!("🚫 {}", lang.t(Text::HideSession)) }</button>
+                            <button onclick={action(on_override, OverrideAction::HideSeries)}>{ format!("🚫 {}", lang.t(Text::HideEveryWeek)) }</button>
+                        }
+                        if !props.alternatives.is_empty() {
+                            <div class="entry-detail-label">{ lang.t(Text::SwapGroup) }</div>
+                            { for props.alternatives.iter().map(|(id, entry_type, date, start_time)| html! {
+                                <button onclick={action(on_override, OverrideAction::SwapTo(*id))}>
+                                    { format!(
+                                        "⇄ {} {} {} {}",
+                                        lang.entry_type_short(entry_type),
+                                        lang.weekday_short(date.weekday()),
+                                        lang.date_short(*date),
+                                        start_time.format("%H:%M"),
+                                    ) }
+                                </button>
+                            }) }
+                        }
+                    </div>
+                }
+            </div>
+        </div>
+    }
+}
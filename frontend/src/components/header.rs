@@ -1,40 +1,55 @@
 
-use js_sys::Object;
-use shared::definitions::{MainProgramId, Semester};
+use shared::definitions::{MainProgram, MainProgramId, Program, Semester};
+use shared::search::{SearchHit, SearchResult};
 use wasm_bindgen::JsCast;
-use wasm_bindgen_futures::JsFuture;
-use web_sys::{HtmlAnchorElement, HtmlCanvasElement, HtmlElement, HtmlSelectElement};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
-use crate::logic::bindings::html2canvas;
+use crate::components::program_picker::ProgramPicker;
+use crate::export::ExportFormat;
+use crate::i18n::{use_lang, Lang, Text};
+use crate::logic::colors::Palette;
+use crate::logic::theme::Theme;
 
 #[derive(Properties, PartialEq)]
 pub struct HeaderProps {
-    pub main_program_options: Vec<(MainProgramId, String)>,
+    pub main_programs: Vec<MainProgram>, // sorted by level and year
+    pub programs: Vec<Program>,
     pub selected_main_program: MainProgramId, // currently selected program ID
     pub on_main_program_change: Callback<MainProgramId>,
+    pub selected_track: Option<String>,
+    pub on_track_change: Callback<Option<String>>,
 
     pub semester_options: Vec<(Semester, String)>,
     pub selected_semester: Semester, // currently selected program ID
     pub on_semester_change: Callback<Semester>,
+
+    pub on_export: Callback<ExportFormat>,
+    pub on_export_csv: Callback<()>,
+    pub on_export_ics: Callback<()>,
+
+    pub search_query: String,
+    pub search_results: Vec<SearchResult>,
+    pub on_search_input: Callback<String>,
+    pub on_search_select: Callback<SearchHit>,
+
+    pub on_lang_change: Callback<Lang>,
+
+    pub palette: Palette,
+    pub on_palette_change: Callback<Palette>,
+
+    pub theme: Theme,
+    pub on_theme_change: Callback<Theme>,
+
+    pub print_weeks: u32, // weeks per printout, from the shown one on
+    pub on_print_weeks_change: Callback<u32>,
+    pub on_print: Callback<()>,
 }
 
 #[function_component(Header)]
 pub fn header(props: &HeaderProps) -> Html {
+    let lang = use_lang();
 
-    let on_main_program_change = {
-        let callback = props.on_main_program_change.clone();
-        Callback::from(move |e: Event| {
-            let target = e.target().unwrap();
-            let select = target.dyn_into::<HtmlSelectElement>().unwrap();
-            let value = select.value();
-            
-            // Parse the MainProgramId from the string value
-            if let Ok(program_id) = value.parse::<u32>() {
-                callback.emit(MainProgramId(program_id));
-            }
-        })
-    };
     let on_semester_change = {
         let callback = props.on_semester_change.clone();
         Callback::from(move |e: Event| {
@@ -49,37 +64,84 @@ pub fn header(props: &HeaderProps) -> Html {
         })
     };
 
-    let onclick = Callback::from(move |_| {
-        wasm_bindgen_futures::spawn_local(async move {
-            let document = web_sys::window().unwrap().document().unwrap();
-            let content = document
-                .get_element_by_id("timetable-wrapper")
-                .unwrap()
-                .dyn_into::<HtmlElement>()
-                .unwrap();
-
-            // temporarily set width
-            content.style().set_property("width", "1100px").unwrap();
-
-            // call html2canvas
-            let options = Object::new();
-            let promise = html2canvas(&content, &options.into());
-            let canvas = JsFuture::from(promise).await.unwrap();
-            let canvas: HtmlCanvasElement = canvas.dyn_into().unwrap();
-
-            // get dataUrl
-            let data_url = canvas.to_data_url().unwrap();
-
-            // create download link
-            let link: HtmlAnchorElement = document.create_element("a").unwrap().dyn_into().unwrap();
-            link.set_href(&data_url);
-            link.set_download("timetable.png");
-            link.click();
-
-            // reset width
-            content.style().set_property("width", "100%").unwrap();
-        });
-    });
+    let on_export = |format: ExportFormat| {
+        let callback = props.on_export.clone();
+        Callback::from(move |_| callback.emit(format))
+    };
+
+    let on_search_input = {
+        let callback = props.on_search_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            callback.emit(input.value());
+        })
+    };
+    let on_search_keydown = {
+        let callback = props.on_search_select.clone();
+        let first = props.search_results.first().map(|r| r.hit);
+        let on_input = props.on_search_input.clone();
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "Enter" => {
+                if let Some(hit) = first {
+                    callback.emit(hit);
+                }
+            }
+            "Escape" => on_input.emit(String::new()),
+            _ => {}
+        })
+    };
+
+    let on_lang_change = {
+        let callback = props.on_lang_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Some(lang) = Lang::from_code(&select.value()) {
+                callback.emit(lang);
+            }
+        })
+    };
+
+    let on_palette_change = {
+        let callback = props.on_palette_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Some(palette) = Palette::from_code(&select.value()) {
+                callback.emit(palette);
+            }
+        })
+    };
+
+    let on_theme_change = {
+        let callback = props.on_theme_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Some(theme) = Theme::from_code(&select.value()) {
+                callback.emit(theme);
+            }
+        })
+    };
+    let on_print_weeks_change = {
+        let callback = props.on_print_weeks_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(weeks) = select.value().parse::<u32>() {
+                callback.emit(weeks);
+            }
+        })
+    };
+    let on_print = {
+        let callback = props.on_print.clone();
+        Callback::from(move |_| callback.emit(()))
+    };
+
+    let on_export_csv = {
+        let callback = props.on_export_csv.clone();
+        Callback::from(move |_| callback.emit(()))
+    };
+    let on_export_ics = {
+        let callback = props.on_export_ics.clone();
+        Callback::from(move |_| callback.emit(()))
+    };
     
     html! {
         <div class="header">
@@ -87,23 +149,55 @@ pub fn header(props: &HeaderProps) -> Html {
                 // <img src="static/logo.png" alt="logo" />
                 { "🐵" }
             </a>
-            <div class="title">{ "TIMETABLE" }</div>
+            <div class="title">{ lang.t(Text::Title) }</div>
+            <div class="search">
+                <input
+                    type="search"
+                    placeholder={lang.t(Text::Search)}
+                    value={props.search_query.clone()}
+                    oninput={on_search_input}
+                    onkeydown={on_search_keydown}
+                />
+                if !props.search_results.is_empty() {
+                    <ul class="search-results">
+                        { for props.search_results.iter().map(|result| {
+                            let hit = result.hit;
+                            let callback = props.on_search_select.clone();
+                            let kind = match hit {
+                                SearchHit::Subject(_) => "📘",
+                                SearchHit::Teacher(_) => "👤",
+                                SearchHit::Classroom(_) => "🚪",
+                            };
+                            html! {
+                                <li onclick={Callback::from(move |_| callback.emit(hit))}>
+                                    { format!("{kind} {}", result.label) }
+                                </li>
+                            }
+                        }) }
+                    </ul>
+                }
+            </div>
             <div class="download-save-discard-select">
-                <button id="download-button" onclick={onclick} title="Download timetable">{ "📸" }</button>
-                // <button id="discard-button" title="Reset">{ "🗑️" }</button>
-                <select
-                    id="main-program-select"
-                    onchange={on_main_program_change}
-                >
-                    { for props.main_program_options.iter().map(|(program_id, name)| html! {
-                        <option 
-                            value={program_id.0.to_string()}
-                            selected={*program_id == props.selected_main_program}
-                        >
-                            { name }
-                        </option>
+                <button id="download-button" onclick={on_export(ExportFormat::Png)} title={lang.t(Text::DownloadPng)}>{ "📸" }</button>
+                <button id="svg-button" onclick={on_export(ExportFormat::Svg)} title={lang.t(Text::DownloadSvg)}>{ "🖼️" }</button>
+                <button id="pdf-button" onclick={on_export(ExportFormat::Pdf)} title={lang.t(Text::DownloadPdf)}>{ "📑" }</button>
+                <button id="csv-button" onclick={on_export_csv} title={lang.t(Text::DownloadCsv)}>{ "📄" }</button>
+                <button id="ics-button" onclick={on_export_ics} title={lang.t(Text::DownloadIcs)}>{ "📅" }</button>
+                <button id="print-button" onclick={on_print} title={lang.t(Text::Print)}>{ "🖨️" }</button>
+                <select id="print-weeks-select" onchange={on_print_weeks_change}>
+                    { for (1..=4).map(|weeks| html! {
+                        <option value={weeks.to_string()} selected={weeks == props.print_weeks}>{ lang.weeks(weeks) }</option>
                     }) }
                 </select>
+                // <button id="discard-button" title="Reset">{ "🗑️" }</button>
+                <ProgramPicker
+                    main_programs={props.main_programs.clone()}
+                    programs={props.programs.clone()}
+                    selected_main_program={props.selected_main_program}
+                    on_main_program_change={props.on_main_program_change.clone()}
+                    selected_track={props.selected_track.clone()}
+                    on_track_change={props.on_track_change.clone()}
+                />
                 <select
                     id="semester-select"
                     onchange={on_semester_change}
@@ -117,6 +211,21 @@ pub fn header(props: &HeaderProps) -> Html {
                         </option>
                     }) }
                 </select>
+                <select id="theme-select" onchange={on_theme_change}>
+                    { for Theme::ALL.iter().map(|t| html! {
+                        <option value={t.code()} selected={*t == props.theme}>{ lang.t(t.name()) }</option>
+                    }) }
+                </select>
+                <select id="palette-select" onchange={on_palette_change}>
+                    { for Palette::ALL.iter().map(|p| html! {
+                        <option value={p.code()} selected={*p == props.palette}>{ lang.t(p.name()) }</option>
+                    }) }
+                </select>
+                <select id="lang-select" onchange={on_lang_change}>
+                    { for Lang::ALL.iter().map(|l| html! {
+                        <option value={l.code()} selected={*l == lang}>{ l.name() }</option>
+                    }) }
+                </select>
             </div>
         </div>
     }
@@ -4,4 +4,15 @@ pub mod calendar;
 pub mod timetable;
 pub mod entry_box;
 pub mod subject_abbr_container;
-pub mod subject_select_container;
\ No newline at end of file
+pub mod subject_select_container;
+pub mod personal_events;
+pub mod entry_detail;
+pub mod view_banner;
+pub mod month_view;
+pub mod semester_view;
+pub mod day_view;
+pub mod update_banner;
+pub mod print_legend;
+pub mod program_picker;
+pub mod my_group;
+pub mod overrides_list;
\ No newline at end of file
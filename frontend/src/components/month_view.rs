@@ -0,0 +1,57 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use yew::prelude::*;
+
+use crate::components::entry_box::EntryBoxProps;
+use crate::i18n::use_lang;
+use crate::logic::date::month_mondays;
+
+const MAX_ENTRIES_PER_DAY: usize = 6;
+
+#[derive(Properties, PartialEq)]
+pub struct MonthViewProps {
+    pub month: NaiveDate, // first day of the month
+    pub entries: Vec<EntryBoxProps>,
+    pub work_free_days: Vec<NaiveDate>,
+    pub on_day_select: Callback<NaiveDate>,
+}
+
+#[function_component(MonthView)]
+pub fn month_view(props: &MonthViewProps) -> Html {
+    let lang = use_lang();
+    let mut entries: Vec<&EntryBoxProps> = props.entries.iter().collect();
+    entries.sort_by_key(|e| (e.date, e.start_time));
+
+    html! {
+        <div id="month-view">
+            { for (0..7).map(|i| html! {
+                <div class="month-day-label">{ lang.weekday_short(Weekday::try_from(i as u8).unwrap()) }</div>
+            }) }
+            { for month_mondays(props.month).into_iter().flat_map(|monday| (0..7).map(move |i| monday + Duration::days(i))).map(|date| {
+                let day_entries: Vec<&&EntryBoxProps> = entries.iter().filter(|e| e.date == date).collect();
+                let hidden = day_entries.len().saturating_sub(MAX_ENTRIES_PER_DAY);
+                let class = classes!(
+                    "month-day",
+                    (date.month() != props.month.month()).then_some("other-month"),
+                    props.work_free_days.contains(&date).then_some("day-work-free"),
+                );
+                let on_click = {
+                    let callback = props.on_day_select.clone();
+                    Callback::from(move |_| callback.emit(date))
+                };
+                html! {
+                    <div {class} onclick={on_click}>
+                        <div class="month-day-number">{ date.day() }</div>
+                        { for day_entries.iter().take(MAX_ENTRIES_PER_DAY).map(|e| html! {
+                            <div class="month-entry" style={format!("background-color: {}; color: {}", e.color.css(), e.color.text_color().css())}>
+                                { format!("{} {} {}", e.start_time.format("%H:%M"), e.subject_abbr, if e.personal { String::new() } else { lang.entry_type_short(&e.entry_type) }) }
+                            </div>
+                        }) }
+                        if hidden > 0 {
+                            <div class="month-more">{ format!("+{hidden}") }</div>
+                        }
+                    </div>
+                }
+            }) }
+        </div>
+    }
+}
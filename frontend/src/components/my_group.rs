@@ -0,0 +1,52 @@
+use shared::definitions::{EntryType, ExerciseType};
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::i18n::{use_lang, Text};
+use crate::logic::profile::Profile;
+
+#[derive(Properties, PartialEq)]
+pub struct MyGroupProps {
+    pub tutorial_groups: Vec<u32>, // every group number some subject has
+    pub lab_groups: Vec<u32>,
+    pub profile: Profile,
+    pub on_change: Callback<Profile>,
+}
+
+/// Tutorial and lab group picked for subjects I add, groups stay switchable per subject
+#[function_component(MyGroup)]
+pub fn my_group(props: &MyGroupProps) -> Html {
+    let lang = use_lang();
+
+    let select = |groups: &[u32], selected: Option<u32>, entry_type: EntryType, set: fn(&mut Profile, Option<u32>)| {
+        let onchange = {
+            let callback = props.on_change.clone();
+            let profile = props.profile;
+            Callback::from(move |e: Event| {
+                let select: HtmlSelectElement = e.target_unchecked_into();
+                let mut profile = profile;
+                set(&mut profile, select.value().parse().ok());
+                callback.emit(profile);
+            })
+        };
+        html! {
+            <label>
+                { lang.entry_type(&entry_type) }
+                <select {onchange}>
+                    <option value="" selected={selected.is_none()}>{ lang.t(Text::AllGroups) }</option>
+                    { for groups.iter().map(|g| html! {
+                        <option value={g.to_string()} selected={selected == Some(*g)}>{ format!("S{g}") }</option>
+                    }) }
+                </select>
+            </label>
+        }
+    };
+
+    html! {
+        <div id="my-group" title={lang.t(Text::MyGroupHint)}>
+            <span class="label">{ lang.t(Text::MyGroup) }</span>
+            { select(&props.tutorial_groups, props.profile.tutorial_group, EntryType::Exercise(ExerciseType::Tutorial(vec![])), |p, g| p.tutorial_group = g) }
+            { select(&props.lab_groups, props.profile.lab_group, EntryType::Exercise(ExerciseType::Lab(vec![])), |p, g| p.lab_group = g) }
+        </div>
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::Datelike;
+use shared::db::Database;
+use shared::definitions::SubjectId;
+use yew::prelude::*;
+
+use crate::i18n::{use_lang, Lang, Text};
+use crate::logic::overrides::{OverrideItem, Overrides};
+
+#[derive(Properties, PartialEq)]
+pub struct OverridesListProps {
+    pub items: Vec<(OverrideItem, String)>, // item, label from `override_label`
+    pub on_remove: Callback<OverrideItem>,
+}
+
+/// Hidden and swapped sessions, each can be undone
+#[function_component(OverridesList)]
+pub fn overrides_list(props: &OverridesListProps) -> Html {
+    let lang = use_lang();
+
+    html! {
+        <div id="overrides">
+            <span class="label">{ lang.t(Text::Overrides) }</span>
+            { for props.items.iter().map(|(item, label)| {
+                let on_remove = {
+                    let callback = props.on_remove.clone();
+                    let item = item.clone();
+                    Callback::from(move |_| callback.emit(item.clone()))
+                };
+                html! {
+                    <span class="override">
+                        { label }
+                        <button onclick={on_remove} title={lang.t(Text::ResetOverride)}>{ "↺" }</button>
+                    </span>
+                }
+            }) }
+        </div>
+    }
+}
+
+/// e.g. "🚫 MLTR VL(S1) Tor 14.10. 10:00" or "🚫 MLTR P Tor 10:00 · Vsak teden"
+pub fn override_labels(db: &Database, lang: Lang, overrides: &Overrides) -> Vec<(OverrideItem, String)> {
+    let abbr = |id: &SubjectId| db.subjects.get(id).map(|s| s.abbr.clone()).unwrap_or_default();
+    overrides
+        .items()
+        .into_iter()
+        .filter_map(|item| {
+            let label = match &item {
+                OverrideItem::Hidden(id) | OverrideItem::Shown(id) => {
+                    let entry = db.entry(id)?;
+                    format!(
+                        "{} {} {} {} {} {}",
+                        if matches!(item, OverrideItem::Hidden(_)) { "🚫" } else { "➕" },
+                        abbr(&entry.subject_id),
+                        lang.entry_type_short(&entry.entry_type),
+                        lang.weekday_short(entry.date.weekday()),
+                        lang.date_short(entry.date),
+                        entry.start_time.format("%H:%M"),
+                    )
+                }
+                OverrideItem::HiddenSeries(series) => format!(
+                    "🚫 {} {} {} {} · {}",
+                    abbr(&series.subject_id),
+                    lang.entry_type_short(&series.entry_type),
+                    lang.weekday_short(series.weekday),
+                    series.start_time.format("%H:%M"),
+                    lang.t(Text::EveryWeek),
+                ),
+            };
+            Some((item, label))
+        })
+        .collect()
+}
@@ -0,0 +1,127 @@
+use chrono::{NaiveDate, NaiveTime, Weekday};
+use shared::definitions::Minutes;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::i18n::{use_lang, Text};
+use crate::logic::personal::{PersonalEvent, Repeat};
+use crate::utils::Color;
+
+const WEEKDAYS: [Weekday; 5] = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+
+#[derive(Properties, PartialEq)]
+pub struct PersonalEventsProps {
+    pub events: Vec<PersonalEvent>,
+    pub on_add: Callback<PersonalEvent>,
+    pub on_remove: Callback<u32>,
+}
+
+#[function_component(PersonalEvents)]
+pub fn personal_events(props: &PersonalEventsProps) -> Html {
+    let lang = use_lang();
+    let title_ref = use_node_ref();
+    let repeat_ref = use_node_ref();
+    let weekday_ref = use_node_ref();
+    let date_ref = use_node_ref();
+    let start_ref = use_node_ref();
+    let duration_ref = use_node_ref();
+    let color_ref = use_node_ref();
+    let error = use_state(|| None::<Text>);
+    let weekly = use_state(|| true);
+
+    let on_repeat_change = {
+        let weekly = weekly.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            weekly.set(select.value() == "weekly");
+        })
+    };
+
+    let on_add = {
+        let callback = props.on_add.clone();
+        let next_id = props.events.iter().map(|e| e.id + 1).max().unwrap_or(1);
+        let refs = (title_ref.clone(), repeat_ref.clone(), weekday_ref.clone(), date_ref.clone(), start_ref.clone(), duration_ref.clone(), color_ref.clone());
+        let error = error.clone();
+        Callback::from(move |_| {
+            let (title, repeat, weekday, date, start, duration, color) = &refs;
+            let value = |r: &NodeRef| r.cast::<HtmlInputElement>().map(|i| i.value()).unwrap_or_default();
+            let select_value = |r: &NodeRef| r.cast::<HtmlSelectElement>().map(|s| s.value()).unwrap_or_default();
+
+            let repeat = if select_value(repeat) == "weekly" {
+                let index: usize = select_value(weekday).parse().unwrap_or(0);
+                Some(Repeat::Weekly(WEEKDAYS[index.min(4)]))
+            } else {
+                NaiveDate::parse_from_str(&value(date), "%Y-%m-%d").ok().map(Repeat::Once)
+            };
+            let Some(repeat) = repeat else {
+                error.set(Some(Text::PickDate));
+                return;
+            };
+            let Ok(start_time) = NaiveTime::parse_from_str(&value(start), "%H:%M") else {
+                error.set(Some(Text::PickStartTime));
+                return;
+            };
+
+            let event = PersonalEvent {
+                id: next_id,
+                title: value(title).trim().to_string(),
+                repeat,
+                start_time,
+                duration: Minutes(value(duration).parse().unwrap_or(0)),
+                color: Color::from_hex(&value(color)).unwrap_or(Color { r: 128, g: 128, b: 128 }),
+            };
+            match event.validate() {
+                Ok(()) => {
+                    error.set(None);
+                    callback.emit(event);
+                }
+                Err(err) => error.set(Some(err)),
+            }
+        })
+    };
+
+    html! {
+        <details id="personal-events">
+            <summary>{ format!("{} ({})", lang.t(Text::PersonalEvents), props.events.len()) }</summary>
+            <div class="personal-events-form">
+                <input ref={title_ref} type="text" placeholder={lang.t(Text::EventTitle)} />
+                <select ref={repeat_ref} onchange={on_repeat_change}>
+                    <option value="weekly" selected={*weekly}>{ lang.t(Text::EveryWeek) }</option>
+                    <option value="once" selected={!*weekly}>{ lang.t(Text::Once) }</option>
+                </select>
+                if *weekly {
+                    <select ref={weekday_ref}>
+                        { for WEEKDAYS.iter().enumerate().map(|(i, day)| html! {
+                            <option value={i.to_string()}>{ lang.weekday(*day) }</option>
+                        }) }
+                    </select>
+                } else {
+                    <input ref={date_ref} type="date" />
+                }
+                <input ref={start_ref} type="time" value="16:00" step="300" />
+                <input ref={duration_ref} type="number" value="90" min="5" step="5" title={lang.t(Text::DurationMinutes)} />
+                <input ref={color_ref} type="color" value="#808080" />
+                <button onclick={on_add}>{ lang.t(Text::Add) }</button>
+                if let Some(err) = &*error {
+                    <span class="personal-events-error">{ lang.t(*err) }</span>
+                }
+            </div>
+            <ul class="personal-events-list">
+                { for props.events.iter().map(|event| {
+                    let id = event.id;
+                    let on_remove = props.on_remove.clone();
+                    let when = match &event.repeat {
+                        Repeat::Weekly(day) => lang.weekday(*day).to_string(),
+                        Repeat::Once(date) => lang.date(*date),
+                    };
+                    html! {
+                        <li style={format!("border-left: 6px solid {}", event.color.css())}>
+                            { format!("{} - {} {}-{}", event.title, when, event.start_time.format("%H:%M"), event.end_time().format("%H:%M")) }
+                            <button onclick={Callback::from(move |_| on_remove.emit(id))} title={lang.t(Text::Remove)}>{ "✕" }</button>
+                        </li>
+                    }
+                }) }
+            </ul>
+        </details>
+    }
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use shared::definitions::{EntryType, ExerciseType, Subject, SubjectId};
+use yew::prelude::*;
+
+use crate::i18n::{use_lang, Text};
+use crate::logic::visibility::{shown_groups, SubjectsVisibilityMap};
+use crate::utils::Color;
+
+#[derive(Properties, PartialEq)]
+pub struct PrintLegendProps {
+    pub subjects: Vec<Subject>,
+    pub subjects_visibility: SubjectsVisibilityMap,
+    pub subject_colors: HashMap<SubjectId, Color>,
+}
+
+/// Shown subjects and their groups, only on paper (see @media print in style.css)
+#[function_component(PrintLegend)]
+pub fn print_legend(props: &PrintLegendProps) -> Html {
+    let lang = use_lang();
+
+    html! {
+        <div class="print-legend">
+            <div class="print-legend-title">{ lang.t(Text::Legend) }</div>
+            <ul>
+                { for props.subjects.iter().filter_map(|subject| {
+                    let visibility = props.subjects_visibility.get(&subject.id).filter(|v| v.can_show)?;
+                    let color = props.subject_colors.get(&subject.id)?;
+
+                    let mut groups = vec![];
+                    let tutorial: Vec<u32> = shown_groups(&visibility.tutorial_groups).into_iter().collect();
+                    if !tutorial.is_empty() {
+                        groups.push(lang.entry_type_short(&EntryType::Exercise(ExerciseType::Tutorial(tutorial))));
+                    }
+                    let lab: Vec<u32> = shown_groups(&visibility.lab_groups).into_iter().collect();
+                    if !lab.is_empty() {
+                        groups.push(lang.entry_type_short(&EntryType::Exercise(ExerciseType::Lab(lab))));
+                    }
+
+                    Some(html! {
+                        <li>
+                            <span class="print-legend-swatch" style={format!("background-color: {}", color.css())}></span>
+                            <b>{ &subject.abbr }</b>{ format!(" {}", subject.name) }
+                            if !groups.is_empty() {
+                                { format!(" · {}", groups.join(" ")) }
+                            }
+                        </li>
+                    })
+                }) }
+            </ul>
+        </div>
+    }
+}
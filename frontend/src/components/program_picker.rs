@@ -0,0 +1,97 @@
+use shared::definitions::{MainProgram, MainProgramId, Program, StudyLevel};
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::i18n::{use_lang, Text};
+
+#[derive(Properties, PartialEq)]
+pub struct ProgramPickerProps {
+    pub main_programs: Vec<MainProgram>, // sorted by level and year
+    pub programs: Vec<Program>,
+    pub selected_main_program: MainProgramId,
+    pub on_main_program_change: Callback<MainProgramId>,
+    pub selected_track: Option<String>, // track abbr, so it stays picked in the next year
+    pub on_track_change: Callback<Option<String>>,
+}
+
+/// Study level → year → track, the year picks the main program
+#[function_component(ProgramPicker)]
+pub fn program_picker(props: &ProgramPickerProps) -> Html {
+    let lang = use_lang();
+
+    let selected = props.main_programs.iter().find(|mp| mp.id == props.selected_main_program);
+    let selected_level = selected.map(|mp| mp.level);
+    let mut levels: Vec<StudyLevel> = props.main_programs.iter().map(|mp| mp.level).collect();
+    levels.dedup();
+    let years: Vec<&MainProgram> = props.main_programs.iter().filter(|mp| Some(mp.level) == selected_level).collect();
+    let mut tracks: Vec<(&str, &str)> = props.programs
+        .iter()
+        .filter(|p| p.main_program_id == props.selected_main_program)
+        .filter_map(|p| p.track.as_ref())
+        .map(|t| (t.abbr.as_str(), t.name.as_str()))
+        .collect();
+    tracks.sort();
+    tracks.dedup();
+
+    // Same year in the other level if it has one
+    let on_level_change = {
+        let callback = props.on_main_program_change.clone();
+        let main_programs = props.main_programs.clone();
+        let year = selected.map(|mp| mp.year);
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let Some(level) = StudyLevel::from_code(&select.value()) else {
+                return;
+            };
+            let mut of_level = main_programs.iter().filter(|mp| mp.level == level);
+            let first = of_level.clone().next();
+            if let Some(mp) = of_level.find(|mp| Some(mp.year) == year).or(first) {
+                callback.emit(mp.id);
+            }
+        })
+    };
+    let on_year_change = {
+        let callback = props.on_main_program_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(id) = select.value().parse::<u32>() {
+                callback.emit(MainProgramId(id));
+            }
+        })
+    };
+    let on_track_change = {
+        let callback = props.on_track_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let value = select.value();
+            callback.emit((!value.is_empty()).then_some(value));
+        })
+    };
+
+    html! {
+        <>
+            <select id="level-select" onchange={on_level_change}>
+                { for levels.iter().map(|level| html! {
+                    <option value={level.code()} selected={Some(*level) == selected_level}>{ lang.level(*level) }</option>
+                }) }
+            </select>
+            <select id="main-program-select" onchange={on_year_change}>
+                { for years.iter().map(|mp| html! {
+                    <option value={mp.id.0.to_string()} selected={mp.id == props.selected_main_program} title={mp.name.clone()}>
+                        { lang.study_year(mp.year) }
+                    </option>
+                }) }
+            </select>
+            if !tracks.is_empty() {
+                <select id="track-select" onchange={on_track_change}>
+                    <option value="" selected={props.selected_track.is_none()}>{ lang.t(Text::AllTracks) }</option>
+                    { for tracks.iter().map(|(abbr, name)| html! {
+                        <option value={abbr.to_string()} selected={props.selected_track.as_deref() == Some(*abbr)}>
+                            { format!("{name} ({abbr})") }
+                        </option>
+                    }) }
+                </select>
+            }
+        </>
+    }
+}
@@ -0,0 +1,62 @@
+use chrono::{Duration, NaiveDate, Weekday};
+use shared::definitions::Semester;
+use shared::time::semester_mondays;
+use yew::prelude::*;
+
+use crate::components::entry_box::EntryBoxProps;
+use crate::i18n::use_lang;
+
+
+#[derive(Properties, PartialEq)]
+pub struct SemesterViewProps {
+    pub semester: Semester,
+    pub entries: Vec<EntryBoxProps>,
+    pub work_free_days: Vec<NaiveDate>,
+    pub on_day_select: Callback<NaiveDate>,
+}
+
+/// One row per teaching week, one small mark per session
+#[function_component(SemesterView)]
+pub fn semester_view(props: &SemesterViewProps) -> Html {
+    let lang = use_lang();
+    let mut entries: Vec<&EntryBoxProps> = props.entries.iter().collect();
+    entries.sort_by_key(|e| (e.date, e.start_time));
+
+    html! {
+        <div id="semester-view">
+            <div class="semester-week-label"></div>
+            { for [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri].iter().map(|day| html! {
+                <div class="semester-day-label">{ lang.weekday_short(*day) }</div>
+            }) }
+            { for semester_mondays(&props.semester).into_iter().enumerate().map(|(i, monday)| html! {
+                <>
+                    <div class="semester-week-label">
+                        { format!("{}. {}", i + 1, lang.date_short(monday)) }
+                    </div>
+                    { for (0..5).map(|d| {
+                        let date = monday + Duration::days(d);
+                        let class = classes!(
+                            "semester-day",
+                            props.work_free_days.contains(&date).then_some("day-work-free"),
+                        );
+                        let on_click = {
+                            let callback = props.on_day_select.clone();
+                            Callback::from(move |_| callback.emit(date))
+                        };
+                        html! {
+                            <div {class} onclick={on_click} title={lang.date(date)}>
+                                { for entries.iter().filter(|e| e.date == date).map(|e| html! {
+                                    <span
+                                        class="semester-mark"
+                                        style={format!("background-color: {}", e.color.css())}
+                                        title={format!("{} {} {}", e.start_time.format("%H:%M"), e.subject_abbr, lang.entry_type_short(&e.entry_type))}
+                                    ></span>
+                                }) }
+                            </div>
+                        }
+                    }) }
+                </>
+            }) }
+        </div>
+    }
+}
@@ -37,7 +37,7 @@ pub fn subject_abbr_container(props: &SubjectAbbrProps) -> Html {
                             if visibility.can_show { "active-button" } else { "" }
                         )}
                         onclick={on_click}
-                        style={format!("background-color: {}", color.css())}
+                        style={format!("background-color: {}; color: {}", color.css(), color.text_color().css())}
                     >
                         { &subject_abbr }
                     </button>
@@ -1,7 +1,9 @@
 use shared::definitions::{Subject, SubjectId};
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 use std::collections::HashMap;
 
+use crate::i18n::{use_lang, Text};
 use crate::{logic::visibility::SubjectsVisibilityMap, utils::Color};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -12,16 +14,19 @@ pub enum GroupType {
 
 #[derive(Properties, PartialEq, Clone)]
 pub struct SubjectContainerProps {
-    pub subjects: Vec<Subject>,
+    pub subjects: Vec<Subject>, // my subjects, from any program
+    pub subject_programs: HashMap<SubjectId, String>, // program name of subjects outside the picked program
     pub subjects_visibility: SubjectsVisibilityMap,
     pub subject_colors: HashMap<SubjectId, Color>,
     pub on_toggle_subject: Callback<SubjectId>,
     pub on_toggle_group: Callback<(SubjectId, GroupType, u32)>, 
+    pub on_color_change: Callback<(SubjectId, Option<Color>)>, // None resets to the palette
 }
 
 
 #[function_component(SubjectContainer)]
 pub fn subject_container(props: &SubjectContainerProps) -> Html {
+    let lang = use_lang();
     html! {
         <div id="subjects-container">
             { for props.subjects.iter().map(|subject| {
@@ -37,10 +42,48 @@ pub fn subject_container(props: &SubjectContainerProps) -> Html {
                     .cloned()
                     .unwrap();
 
+                let on_color_input = {
+                    let id = subject.id;
+                    let callback = props.on_color_change.clone();
+                    Callback::from(move |e: InputEvent| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        if let Some(color) = Color::from_hex(&input.value()) {
+                            callback.emit((id, Some(color)));
+                        }
+                    })
+                };
+                let on_remove = {
+                    let id = subject.id;
+                    let callback = props.on_toggle_subject.clone();
+                    Callback::from(move |_| callback.emit(id))
+                };
+                let on_color_reset = {
+                    let id = subject.id;
+                    let callback = props.on_color_change.clone();
+                    Callback::from(move |_| callback.emit((id, None)))
+                };
+
                 // Whole subject container
                 html! {
-                    <div class="subject-select-container" style={format!("background-color: {}", bg_color.css())}>
-                        <div class="title">{ &subject.name }</div>
+                    <div
+                        class="subject-select-container"
+                        style={format!("background-color: {}; color: {}", bg_color.css(), bg_color.text_color().css())}
+                    >
+                        <div class="title-row">
+                            <div class="title">{ &subject.name }</div>
+                            <input
+                                type="color"
+                                class="color-picker"
+                                title={lang.t(Text::SubjectColor)}
+                                value={bg_color.hex()}
+                                oninput={on_color_input}
+                            />
+                            <button class="color-reset" title={lang.t(Text::ResetColor)} onclick={on_color_reset}>{ "↺" }</button>
+                            <button class="subject-remove" title={lang.t(Text::RemoveSubject)} onclick={on_remove}>{ "✕" }</button>
+                        </div>
+                        if let Some(program) = props.subject_programs.get(&subject.id) {
+                            <div class="direction-label">{ program }</div>
+                        }
 
                         // Tutorial groups
                         <div class="exercises-container">
@@ -1,7 +1,9 @@
+use shared::definitions::EntryId;
 use yew::prelude::*;
-use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use chrono::{Duration, NaiveDate, Weekday};
 
 use crate::components::entry_box::{EntryBox, EntryBoxProps};
+use crate::i18n::{use_lang, Text};
 
 
 
@@ -11,18 +13,16 @@ pub struct TimetableProps {
     pub year: i32,
     pub entries: Vec<EntryBoxProps>,
     pub work_free_days: Vec<NaiveDate>,
+    pub on_entry_click: Callback<EntryId>,
+    #[prop_or_default]
+    pub print_copy: bool, // extra printed weeks, only the shown week has the id
 }
 
 
 #[function_component(Timetable)]
 pub fn timetable(props: &TimetableProps) -> Html {
-    let days = [
-        ("Ponedeljek", Weekday::Mon),
-        ("Torek", Weekday::Tue),
-        ("Sreda", Weekday::Wed),
-        ("Četrtek", Weekday::Thu),
-        ("Petek", Weekday::Fri),
-    ];
+    let lang = use_lang();
+    let days = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
 
     // Monday of the ISO week
     let monday = NaiveDate::from_isoywd_opt(props.year, props.current_week as u32, Weekday::Mon)
@@ -44,10 +44,10 @@ pub fn timetable(props: &TimetableProps) -> Html {
 
 
     html! {
-        <div id="timetable-wrapper">
-            <div id="timetable">
-                <div id="days">
-                    { for days.iter().enumerate().map(|(i, (label, weekday))| {
+        <div id={(!props.print_copy).then_some("timetable-wrapper")} class="timetable-wrapper">
+            <div class="timetable">
+                <div class="days">
+                    { for days.iter().enumerate().map(|(i, weekday)| {
                         let left = format!("{:.2}%", i as f64 * 20.0);
                         let style = format!("--day-width: 20.00%; left: {left};");
 
@@ -61,7 +61,7 @@ pub fn timetable(props: &TimetableProps) -> Html {
                         let class = if is_work_free { "day day-work-free" } else { "day" };
 
                         // optional label for work-free day
-                        let label_text = format!("{} - {:02}.{:02}.{}", label, date.day(), date.month(), date.year());
+                        let label_text = format!("{} - {}", lang.weekday(*weekday), lang.date(date));
 
                         html! {
                             <div {class} style={style}>
@@ -70,28 +70,40 @@ pub fn timetable(props: &TimetableProps) -> Html {
                         }
                     }) }
                 </div>
-                <div id="hours">{ for hour_nodes }</div>
-                <div id="entries">
-                    { for props.entries.iter().map(|entry| html! {
-                        <EntryBox
-                            subject_id={entry.subject_id}
-                            subject_name={entry.subject_name.clone()}
-                            subject_abbr={entry.subject_abbr.clone()}
-                            entry_type={entry.entry_type.clone()}
-                            teachers={entry.teachers.clone()}
-                            classroom={entry.classroom.clone()}
-                            day={entry.day}
-                            date={entry.date}
-                            start_time={entry.start_time}
-                            duration={entry.duration}
-                            offset_x_multiplier={entry.offset_x_multiplier}
-                            total_columns={entry.total_columns}
-                            color={entry.color.clone()}
-                        />
+                <div class="hours">{ for hour_nodes }</div>
+                <div class="entries">
+                    { for props.entries.iter().map(|entry| {
+                        let onclick = entry.entry_id.map(|id| {
+                            let on_entry_click = props.on_entry_click.clone();
+                            Callback::from(move |_: MouseEvent| on_entry_click.emit(id))
+                        });
+                        html! {
+                            <div class="entry-click" {onclick}>
+                                <EntryBox
+                                    entry_id={entry.entry_id}
+                                    subject_id={entry.subject_id}
+                                    subject_name={entry.subject_name.clone()}
+                                    subject_abbr={entry.subject_abbr.clone()}
+                                    entry_type={entry.entry_type.clone()}
+                                    teachers={entry.teachers.clone()}
+                                    classroom={entry.classroom.clone()}
+                                    day={entry.day}
+                                    date={entry.date}
+                                    start_time={entry.start_time}
+                                    duration={entry.duration}
+                                    end_time={entry.end_time}
+                                    offset_x_multiplier={entry.offset_x_multiplier}
+                                    total_columns={entry.total_columns}
+                                    color={entry.color.clone()}
+                                    personal={entry.personal}
+                                    clash={entry.clash}
+                                />
+                            </div>
+                        }
                     }) }
                 </div>
                 <div class="author-mark">
-                    <div class="made-by">{ "made by" }</div>
+                    <div class="made-by">{ lang.t(Text::MadeBy) }</div>
                     <div class="author">{ "Axstr0n" }</div>
                 </div>
             </div>
@@ -0,0 +1,32 @@
+use yew::prelude::*;
+
+use crate::i18n::{use_lang, Text};
+
+#[derive(Properties, PartialEq)]
+pub struct UpdateBannerProps {
+    pub on_dismiss: Callback<()>,
+}
+
+/// Offers a reload when the server has newer timetable data than the running app
+#[function_component(UpdateBanner)]
+pub fn update_banner(props: &UpdateBannerProps) -> Html {
+    let lang = use_lang();
+    // pages are fetched network first, so a reload picks up the new build
+    let on_reload = Callback::from(|_| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.location().reload();
+        }
+    });
+    let on_dismiss = {
+        let callback = props.on_dismiss.clone();
+        Callback::from(move |_| callback.emit(()))
+    };
+
+    html! {
+        <div id="update-banner">
+            <div class="label">{ lang.t(Text::NewDataAvailable) }</div>
+            <button onclick={on_reload}>{ lang.t(Text::Reload) }</button>
+            <button onclick={on_dismiss}>{ lang.t(Text::Later) }</button>
+        </div>
+    }
+}
@@ -0,0 +1,26 @@
+use yew::prelude::*;
+
+use crate::i18n::{use_lang, Text};
+
+#[derive(Properties, PartialEq)]
+pub struct ViewBannerProps {
+    pub label: String,
+    pub on_back: Callback<()>,
+}
+
+/// Shown instead of the subject pickers while viewing a teacher's or room's timetable
+#[function_component(ViewBanner)]
+pub fn view_banner(props: &ViewBannerProps) -> Html {
+    let lang = use_lang();
+    let on_back = {
+        let callback = props.on_back.clone();
+        Callback::from(move |_| callback.emit(()))
+    };
+
+    html! {
+        <div id="view-banner">
+            <div class="label">{ &props.label }</div>
+            <button onclick={on_back}>{ lang.t(Text::BackToMyTimetable) }</button>
+        </div>
+    }
+}
@@ -1,18 +1,25 @@
+use std::rc::Rc;
+
+use shared::bundle::fingerprint;
 use shared::db::Database;
 
+const BUNDLE: &[u8] = include_bytes!("../../static/timetable.bin");
+
+thread_local! {
+    // decoded on first use, renders and callbacks share it
+    static DATABASE: Rc<Database> = Rc::new(Database::from_bundle(BUNDLE).unwrap());
+}
+
 pub struct FrontendDatabase;
 
 impl FrontendDatabase {
-    pub fn load() -> Database {
-        Database {
-            main_programs: serde_json::from_str(include_str!("../../static/main_programs.json")).unwrap(),
-            programs: serde_json::from_str(include_str!("../../static/programs.json")).unwrap(),
-            semesters: serde_json::from_str(include_str!("../../static/semesters.json")).unwrap(),
-            subjects: serde_json::from_str(include_str!("../../static/subjects.json")).unwrap(),
-            teachers: serde_json::from_str(include_str!("../../static/teachers.json")).unwrap(),
-            classrooms: serde_json::from_str(include_str!("../../static/classrooms.json")).unwrap(),
-            work_free_days: serde_json::from_str(include_str!("../../static/work_free_days.json")).unwrap(),
-            entries: serde_json::from_str(include_str!("../../static/entries.json")).unwrap(),
-        }
+    /// The compact bundle written by `scraper bundle`, decoded once
+    pub fn load() -> Rc<Database> {
+        DATABASE.with(Rc::clone)
+    }
+
+    /// Compared with static/data_manifest.json to notice newer data
+    pub fn fingerprint() -> String {
+        fingerprint(BUNDLE)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,204 @@
+//! Timetable export drawn from `EntryBoxProps`, without touching the DOM.
+//! Pages are laid out once as shapes in points (A4 landscape) and written as SVG or PDF.
+
+pub mod pdf;
+pub mod png;
+pub mod svg;
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use shared::definitions::{EntryType, Semester};
+use shared::time::{duration_to_percent, time_to_percent};
+
+use crate::components::entry_box::EntryBoxProps;
+use crate::i18n::Lang;
+use crate::logic::entries::{arrange_columns, EntrySource};
+use crate::utils::Color;
+
+pub const PAGE_WIDTH: f32 = 842.0;
+pub const PAGE_HEIGHT: f32 = 595.0;
+
+const MARGIN: f32 = 24.0;
+const TITLE_HEIGHT: f32 = 44.0;
+const DAY_LABEL_HEIGHT: f32 = 18.0;
+const HOURS_WIDTH: f32 = 28.0;
+const BOX_GAP: f32 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Png,
+    Svg,
+    Pdf,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    Rect { x: f32, y: f32, w: f32, h: f32, fill: Color, stroke: Option<Color>, dashed: bool },
+    Line { x1: f32, y1: f32, x2: f32, y2: f32, color: Color, dashed: bool },
+    /// `y` is the baseline
+    Text { x: f32, y: f32, size: f32, bold: bool, color: Color, text: String },
+}
+
+/// One week of the timetable with a title block
+#[derive(Clone, PartialEq)]
+pub struct WeekPage {
+    pub title: String,
+    pub subtitle: String,
+    pub days: Vec<(String, bool)>, // label, work-free
+    pub entries: Vec<EntryBoxProps>,
+}
+
+impl WeekPage {
+    /// `title` names the program, teacher or room
+    pub fn new(
+        source: &EntrySource,
+        lang: Lang,
+        title: &str,
+        semester: Semester,
+        monday: NaiveDate,
+        work_free_days: &[NaiveDate],
+    ) -> WeekPage {
+        let friday = monday + Duration::days(4);
+        let iso = monday.iso_week();
+        let days = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]
+            .iter()
+            .map(|day| {
+                let date = monday + Duration::days(day.num_days_from_monday() as i64);
+                (format!("{} {}", lang.weekday(*day), lang.date_short(date)), work_free_days.contains(&date))
+            })
+            .collect();
+
+        WeekPage {
+            title: title.to_string(),
+            subtitle: format!("{} · {} - {}", lang.semester(semester.0), lang.date(monday), lang.date(friday)),
+            days,
+            entries: arrange_columns(source.week(iso.year(), iso.week() as i32)),
+        }
+    }
+
+    pub fn shapes(&self, lang: Lang) -> Vec<Shape> {
+        let black = Color { r: 0, g: 0, b: 0 };
+        let grey = Color { r: 190, g: 190, b: 190 };
+        let light = Color { r: 225, g: 225, b: 225 };
+        let shaded = Color { r: 230, g: 230, b: 230 };
+
+        let grid_left = MARGIN + HOURS_WIDTH;
+        let grid_top = MARGIN + TITLE_HEIGHT + DAY_LABEL_HEIGHT;
+        let grid_width = PAGE_WIDTH - MARGIN - grid_left;
+        let grid_height = PAGE_HEIGHT - MARGIN - grid_top;
+        let day_width = grid_width / 5.0;
+
+        let mut shapes = vec![
+            Shape::Text { x: MARGIN, y: MARGIN + 18.0, size: 18.0, bold: true, color: black.clone(), text: self.title.clone() },
+            Shape::Text { x: MARGIN, y: MARGIN + 36.0, size: 11.0, bold: false, color: black.clone(), text: self.subtitle.clone() },
+        ];
+
+        // days
+        for (i, (label, work_free)) in self.days.iter().enumerate() {
+            let x = grid_left + i as f32 * day_width;
+            if *work_free {
+                shapes.push(Shape::Rect { x, y: grid_top, w: day_width, h: grid_height, fill: shaded.clone(), stroke: None, dashed: false });
+            }
+            shapes.push(Shape::Text {
+                x: x + 4.0,
+                y: grid_top - 5.0,
+                size: 10.0,
+                bold: true,
+                color: black.clone(),
+                text: fit_text(label, day_width - 8.0, 10.0),
+            });
+            shapes.push(Shape::Line { x1: x, y1: grid_top - DAY_LABEL_HEIGHT, x2: x, y2: grid_top + grid_height, color: grey.clone(), dashed: false });
+        }
+        shapes.push(Shape::Line {
+            x1: grid_left + grid_width,
+            y1: grid_top - DAY_LABEL_HEIGHT,
+            x2: grid_left + grid_width,
+            y2: grid_top + grid_height,
+            color: grey.clone(),
+            dashed: false,
+        });
+
+        // hours 7..20, dashed half hours
+        for half_hour in 0..=26 {
+            let y = grid_top + grid_height * half_hour as f32 / 26.0;
+            let full = half_hour % 2 == 0;
+            shapes.push(Shape::Line {
+                x1: if full { MARGIN } else { grid_left },
+                y1: y,
+                x2: grid_left + grid_width,
+                y2: y,
+                color: if full { grey.clone() } else { light.clone() },
+                dashed: !full,
+            });
+            if full && half_hour < 26 {
+                shapes.push(Shape::Text {
+                    x: MARGIN,
+                    y: y + 10.0,
+                    size: 8.0,
+                    bold: false,
+                    color: black.clone(),
+                    text: format!("{}", 7 + half_hour / 2),
+                });
+            }
+        }
+
+        // entries
+        for entry in &self.entries {
+            let day = entry.day.num_days_from_monday() as f32;
+            if day > 4.0 {
+                continue;
+            }
+            let column_width = day_width / entry.total_columns as f32;
+            let x = grid_left + day * day_width + entry.offset_x_multiplier * column_width + BOX_GAP;
+            let y = grid_top + grid_height * time_to_percent(entry.start_time) / 100.0 + BOX_GAP;
+            let w = column_width - 2.0 * BOX_GAP;
+            let h = grid_height * duration_to_percent(entry.duration) / 100.0 - 2.0 * BOX_GAP;
+
+            shapes.push(Shape::Rect {
+                x,
+                y,
+                w,
+                h,
+                fill: entry.color.clone(),
+                stroke: (entry.personal || entry.entry_type == EntryType::Lecture).then(|| black.clone()),
+                dashed: entry.personal,
+            });
+
+            let title = if text_width(&entry.subject_name, 8.0) <= w - 4.0 { &entry.subject_name } else { &entry.subject_abbr };
+            let mut lines = vec![(title.clone(), true)];
+            if entry.personal {
+                lines.push((format!("{} - {}", entry.start_time.format("%H:%M"), entry.end_time.format("%H:%M")), false));
+            } else {
+                lines.push((lang.entry_type_short(&entry.entry_type), false));
+                lines.push((entry.classroom.clone(), false));
+            }
+            for (i, (text, bold)) in lines.into_iter().enumerate() {
+                let line_y = y + 9.0 + i as f32 * 9.0;
+                if line_y > y + h - 1.0 {
+                    break;
+                }
+                shapes.push(Shape::Text { x: x + 2.0, y: line_y, size: 7.5, bold, color: entry.color.text_color(), text: fit_text(&text, w - 4.0, 7.5) });
+            }
+        }
+
+        shapes
+    }
+}
+
+/// Rough width of Helvetica text, good enough to decide where to cut
+pub fn text_width(text: &str, size: f32) -> f32 {
+    text.chars().count() as f32 * size * 0.52
+}
+
+/// Cuts `text` with an ellipsis so it fits `width`
+pub fn fit_text(text: &str, width: f32, size: f32) -> String {
+    if text_width(text, size) <= width {
+        return text.to_string();
+    }
+    let max_chars = (width / (size * 0.52)).floor() as usize;
+    if max_chars <= 1 {
+        return String::new();
+    }
+    let mut cut: String = text.chars().take(max_chars - 1).collect();
+    cut.push('…');
+    cut
+}
@@ -0,0 +1,127 @@
+//! Minimal PDF 1.4 writer: one A4 landscape page per week, built-in Helvetica fonts.
+
+use std::fmt::Write;
+
+use super::{Shape, WeekPage, PAGE_HEIGHT, PAGE_WIDTH};
+use crate::i18n::Lang;
+use crate::utils::Color;
+
+pub fn render(pages: &[WeekPage], lang: Lang) -> Vec<u8> {
+    // objects 1 catalog, 2 pages, 3 and 4 fonts, then a page and its content per week
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        vec![], // pages, filled in below once the kids are known
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_vec(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>".to_vec(),
+    ];
+
+    let mut kids = vec![];
+    for page in pages {
+        let content = content_stream(page, lang);
+        let page_id = objects.len() + 1;
+        let content_id = page_id + 1;
+        kids.push(format!("{page_id} 0 R"));
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+             /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {content_id} 0 R >>"
+        ).into_bytes());
+
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend(content);
+        stream.extend(b"\nendstream");
+        objects.push(stream);
+    }
+    objects[1] = format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), kids.len()).into_bytes();
+
+    let mut out = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = vec![];
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend(format!("{} 0 obj\n", i + 1).into_bytes());
+        out.extend(object);
+        out.extend(b"\nendobj\n");
+    }
+
+    let xref = out.len();
+    let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        writeln!(trailer, "{offset:010} 00000 n ").unwrap();
+    }
+    write!(trailer, "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n", objects.len() + 1).unwrap();
+    out.extend(trailer.into_bytes());
+    out
+}
+
+fn content_stream(page: &WeekPage, lang: Lang) -> Vec<u8> {
+    let mut out: Vec<u8> = vec![];
+    // PDF y grows upwards
+    let flip = |y: f32| PAGE_HEIGHT - y;
+
+    for shape in page.shapes(lang) {
+        match shape {
+            Shape::Rect { x, y, w, h, fill, stroke, dashed } => {
+                out.extend(format!("{} rg {x:.1} {:.1} {w:.1} {h:.1} re f\n", rgb(&fill), flip(y + h)).into_bytes());
+                if let Some(stroke) = stroke {
+                    out.extend(format!(
+                        "{} RG 0.8 w {} {x:.1} {:.1} {w:.1} {h:.1} re S [] 0 d\n",
+                        rgb(&stroke),
+                        if dashed { "[3 2] 0 d" } else { "" },
+                        flip(y + h)
+                    ).into_bytes());
+                }
+            }
+            Shape::Line { x1, y1, x2, y2, color, dashed } => {
+                out.extend(format!(
+                    "{} RG 0.5 w {} {x1:.1} {:.1} m {x2:.1} {:.1} l S [] 0 d\n",
+                    rgb(&color),
+                    if dashed { "[2 2] 0 d" } else { "" },
+                    flip(y1),
+                    flip(y2)
+                ).into_bytes());
+            }
+            Shape::Text { x, y, size, bold, color, text } => {
+                out.extend(format!(
+                    "BT /{} {size} Tf {} rg {x:.1} {:.1} Td (",
+                    if bold { "F2" } else { "F1" },
+                    rgb(&color),
+                    flip(y)
+                ).into_bytes());
+                out.extend(win_ansi(&text));
+                out.extend(b") Tj ET\n");
+            }
+        }
+    }
+    out
+}
+
+fn rgb(color: &Color) -> String {
+    format!("{:.3} {:.3} {:.3}", color.r as f32 / 255.0, color.g as f32 / 255.0, color.b as f32 / 255.0)
+}
+
+/// Text as an escaped WinAnsi string, characters the standard fonts can't show are simplified
+fn win_ansi(text: &str) -> Vec<u8> {
+    let mut out = vec![];
+    for c in text.chars() {
+        let byte = match c {
+            '(' | ')' | '\\' => {
+                out.push(b'\\');
+                c as u8
+            }
+            ' '..='~' => c as u8,
+            'Š' => 0x8A,
+            'š' => 0x9A,
+            'Ž' => 0x8E,
+            'ž' => 0x9E,
+            '…' => 0x85,
+            '–' => 0x96,
+            'Č' | 'Ć' => b'C',
+            'č' | 'ć' => b'c',
+            'Đ' => b'D',
+            'đ' => b'd',
+            '\u{a0}'..='\u{ff}' => c as u32 as u8,
+            _ => b'?',
+        };
+        out.push(byte);
+    }
+    out
+}
@@ -0,0 +1,31 @@
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement};
+
+use super::{PAGE_HEIGHT, PAGE_WIDTH};
+use crate::utils::download;
+
+// Pixels per point, so the PNG stays sharp when zoomed
+const SCALE: f64 = 2.0;
+
+/// Rasterizes the SVG on a canvas and downloads it as PNG once the image has loaded
+pub fn download_png(svg: &str, filename: &str) {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let image: HtmlImageElement = document.create_element("img").unwrap().dyn_into().unwrap();
+    let canvas: HtmlCanvasElement = document.create_element("canvas").unwrap().dyn_into().unwrap();
+    canvas.set_width((PAGE_WIDTH as f64 * SCALE) as u32);
+    canvas.set_height((PAGE_HEIGHT as f64 * SCALE) as u32);
+
+    let filename = filename.to_string();
+    let loaded = image.clone();
+    EventListener::once(&image, "load", move |_| {
+        let context: CanvasRenderingContext2d = canvas.get_context("2d").unwrap().unwrap().dyn_into().unwrap();
+        context.scale(SCALE, SCALE).unwrap();
+        context.draw_image_with_html_image_element(&loaded, 0.0, 0.0).unwrap();
+        download(&filename, &canvas.to_data_url_with_type("image/png").unwrap());
+    })
+    .forget();
+
+    let encoded = String::from(js_sys::encode_uri_component(svg));
+    image.set_src(&format!("data:image/svg+xml;charset=utf-8,{encoded}"));
+}
@@ -0,0 +1,53 @@
+use std::fmt::Write;
+
+use super::{Shape, WeekPage, PAGE_HEIGHT, PAGE_WIDTH};
+use crate::i18n::Lang;
+
+pub fn render(page: &WeekPage, lang: Lang) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{PAGE_WIDTH}" height="{PAGE_HEIGHT}" viewBox="0 0 {PAGE_WIDTH} {PAGE_HEIGHT}" font-family="Helvetica, Arial, sans-serif">"#
+    ).unwrap();
+    writeln!(out, r#"<rect width="100%" height="100%" fill="white"/>"#).unwrap();
+
+    for shape in page.shapes(lang) {
+        match shape {
+            Shape::Rect { x, y, w, h, fill, stroke, dashed } => {
+                let stroke = match stroke {
+                    Some(color) => format!(
+                        r#" stroke="{}" stroke-width="0.8"{}"#,
+                        color.css(),
+                        if dashed { r#" stroke-dasharray="3 2""# } else { "" }
+                    ),
+                    None => String::new(),
+                };
+                writeln!(out, r#"<rect x="{x:.1}" y="{y:.1}" width="{w:.1}" height="{h:.1}" rx="3" fill="{}"{stroke}/>"#, fill.css()).unwrap();
+            }
+            Shape::Line { x1, y1, x2, y2, color, dashed } => {
+                writeln!(
+                    out,
+                    r#"<line x1="{x1:.1}" y1="{y1:.1}" x2="{x2:.1}" y2="{y2:.1}" stroke="{}" stroke-width="0.5"{}/>"#,
+                    color.css(),
+                    if dashed { r#" stroke-dasharray="2 2""# } else { "" }
+                ).unwrap();
+            }
+            Shape::Text { x, y, size, bold, color, text } => {
+                writeln!(
+                    out,
+                    r#"<text x="{x:.1}" y="{y:.1}" font-size="{size}"{} fill="{}">{}</text>"#,
+                    if bold { r#" font-weight="bold""# } else { "" },
+                    color.css(),
+                    escape(&text)
+                ).unwrap();
+            }
+        }
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
@@ -0,0 +1,331 @@
+//! Slovenian and English UI strings. The language is passed down as a yew context.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use shared::definitions::{EntryType, ExerciseType, StudyLevel};
+
+const STORAGE_KEY: &str = "timetable.lang";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Lang {
+    Sl,
+    En,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Text {
+    Title,
+    MadeBy,
+    Search,
+    DownloadPng,
+    DownloadSvg,
+    DownloadPdf,
+    DownloadCsv,
+    DownloadIcs,
+    Day,
+    Week,
+    Month,
+    Semester,
+    PersonalEvents,
+    EventTitle,
+    EveryWeek,
+    Once,
+    Add,
+    Remove,
+    DurationMinutes,
+    PickDate,
+    PickStartTime,
+    TitleEmpty,
+    WeekdaysOnly,
+    OutsideHours,
+    Close,
+    Teachers,
+    Classroom,
+    ShowTeacherTimetable,
+    ShowRoomTimetable,
+    BackToMyTimetable,
+    WorkFreeDay,
+    NoClasses,
+    NewDataAvailable,
+    Reload,
+    Later,
+    PaletteDefault,
+    PaletteColorBlind,
+    PaletteHighContrast,
+    SubjectColor,
+    ResetColor,
+    ThemeSystem,
+    ThemeLight,
+    ThemeDark,
+    Print,
+    Legend,
+    RemoveSubject,
+    AllTracks,
+    MyGroup,
+    MyGroupHint,
+    AllGroups,
+    Overrides,
+    HideSession,
+    HideEveryWeek,
+    SwapGroup,
+    ResetOverride,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 2] = [Lang::Sl, Lang::En];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lang::Sl => "sl",
+            Lang::En => "en",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Lang> {
+        Lang::ALL.into_iter().find(|lang| code.to_lowercase().starts_with(lang.code()))
+    }
+
+    /// Saved choice, else the browser language, else English
+    pub fn detect() -> Lang {
+        if let Ok(lang) = LocalStorage::get::<Lang>(STORAGE_KEY) {
+            return lang;
+        }
+        web_sys::window()
+            .and_then(|w| w.navigator().language())
+            .and_then(|code| Lang::from_code(&code))
+            .unwrap_or(Lang::En)
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(STORAGE_KEY, self);
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lang::Sl => "Slovenščina",
+            Lang::En => "English",
+        }
+    }
+
+    pub fn t(&self, text: Text) -> &'static str {
+        let (sl, en) = match text {
+            Text::Title => ("URNIK", "TIMETABLE"),
+            Text::MadeBy => ("avtor", "made by"),
+            Text::Search => ("Išči predmete, izvajalce, prostore", "Search subjects, teachers, rooms"),
+            Text::DownloadPng => ("Prenesi teden kot sliko (PNG)", "Download week as image (PNG)"),
+            Text::DownloadSvg => ("Prenesi teden kot vektorsko sliko (SVG)", "Download week as vector image (SVG)"),
+            Text::DownloadPdf => ("Prenesi semester kot PDF", "Download semester as PDF"),
+            Text::DownloadCsv => ("Prenesi semester kot CSV", "Download semester as CSV"),
+            Text::DownloadIcs => ("Prenesi semester kot koledar (ICS)", "Download semester as calendar (ICS)"),
+            Text::Day => ("Dan", "Day"),
+            Text::Week => ("Teden", "Week"),
+            Text::Month => ("Mesec", "Month"),
+            Text::Semester => ("Semester", "Semester"),
+            Text::PersonalEvents => ("Osebni dogodki", "Personal events"),
+            Text::EventTitle => ("Naslov", "Title"),
+            Text::EveryWeek => ("Vsak teden", "Every week"),
+            Text::Once => ("Enkrat", "Once"),
+            Text::Add => ("Dodaj", "Add"),
+            Text::Remove => ("Odstrani", "Remove"),
+            Text::DurationMinutes => ("Trajanje v minutah", "Duration in minutes"),
+            Text::PickDate => ("Izberi datum", "Pick a date"),
+            Text::PickStartTime => ("Izberi začetek", "Pick a start time"),
+            Text::TitleEmpty => ("Naslov je prazen", "Title is empty"),
+            Text::WeekdaysOnly => ("Prikazani so le dnevi od ponedeljka do petka", "Only Monday to Friday can be shown"),
+            Text::OutsideHours => ("Dogodek mora biti med 7:00 in 20:00", "Event must be between 7:00 and 20:00"),
+            Text::Close => ("Zapri", "Close"),
+            Text::Teachers => ("Izvajalci", "Teachers"),
+            Text::Classroom => ("Prostor", "Classroom"),
+            Text::ShowTeacherTimetable => ("Pokaži urnik izvajalca", "Show teacher's timetable"),
+            Text::ShowRoomTimetable => ("Pokaži urnik prostora", "Show room's timetable"),
+            Text::BackToMyTimetable => ("Nazaj na moj urnik", "Back to my timetable"),
+            Text::WorkFreeDay => ("Dela prost dan", "Work-free day"),
+            Text::NoClasses => ("Ni predavanj", "No classes"),
+            Text::NewDataAvailable => ("Na voljo je nov urnik", "New timetable data available"),
+            Text::Reload => ("Osveži", "Reload"),
+            Text::Later => ("Kasneje", "Later"),
+            Text::PaletteDefault => ("Privzete barve", "Default colors"),
+            Text::PaletteColorBlind => ("Za barvno slepe", "Color-blind safe"),
+            Text::PaletteHighContrast => ("Visok kontrast", "High contrast"),
+            Text::SubjectColor => ("Barva predmeta", "Subject color"),
+            Text::ResetColor => ("Ponastavi barvo", "Reset color"),
+            Text::ThemeSystem => ("Sistemska tema", "System theme"),
+            Text::ThemeLight => ("Svetla tema", "Light theme"),
+            Text::ThemeDark => ("Temna tema", "Dark theme"),
+            Text::Print => ("Natisni", "Print"),
+            Text::Legend => ("Izbrani predmeti", "Selected subjects"),
+            Text::RemoveSubject => ("Odstrani iz mojih predmetov", "Remove from my subjects"),
+            Text::AllTracks => ("Vse smeri", "All tracks"),
+            Text::MyGroup => ("Moja skupina", "My group"),
+            Text::MyGroupHint => (
+                "Dodani predmeti prikažejo le to skupino, ali vse, če je predmet nima",
+                "Added subjects show only this group, or all of them if the subject doesn't have it",
+            ),
+            Text::AllGroups => ("vse", "all"),
+            Text::Overrides => ("Spremembe urnika", "Timetable changes"),
+            Text::HideSession => ("Skrij to uro", "Hide this session"),
+            Text::HideEveryWeek => ("Skrij vsak teden", "Hide every week"),
+            Text::SwapGroup => ("Ta teden z drugo skupino", "This week with another group"),
+            Text::ResetOverride => ("Razveljavi", "Undo"),
+        };
+        match self {
+            Lang::Sl => sl,
+            Lang::En => en,
+        }
+    }
+
+    pub fn weekday(&self, day: Weekday) -> &'static str {
+        let names = match self {
+            Lang::Sl => ["Ponedeljek", "Torek", "Sreda", "Četrtek", "Petek", "Sobota", "Nedelja"],
+            Lang::En => ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"],
+        };
+        names[day.num_days_from_monday() as usize]
+    }
+
+    pub fn weekday_short(&self, day: Weekday) -> &'static str {
+        let names = match self {
+            Lang::Sl => ["Pon", "Tor", "Sre", "Čet", "Pet", "Sob", "Ned"],
+            Lang::En => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        };
+        names[day.num_days_from_monday() as usize]
+    }
+
+    /// `month` is 1-based like `NaiveDate::month`
+    pub fn month(&self, month: u32) -> &'static str {
+        let names = match self {
+            Lang::Sl => [
+                "januar", "februar", "marec", "april", "maj", "junij",
+                "julij", "avgust", "september", "oktober", "november", "december",
+            ],
+            Lang::En => [
+                "January", "February", "March", "April", "May", "June",
+                "July", "August", "September", "October", "November", "December",
+            ],
+        };
+        names[(month as usize).clamp(1, 12) - 1]
+    }
+
+    /// 20.10.2026 or 20/10/2026
+    pub fn date(&self, date: NaiveDate) -> String {
+        match self {
+            Lang::Sl => date.format("%d.%m.%Y").to_string(),
+            Lang::En => date.format("%d/%m/%Y").to_string(),
+        }
+    }
+
+    /// 20.10. or 20/10
+    pub fn date_short(&self, date: NaiveDate) -> String {
+        match self {
+            Lang::Sl => date.format("%d.%m.").to_string(),
+            Lang::En => date.format("%d/%m").to_string(),
+        }
+    }
+
+    /// "oktober 2026" or "October 2026"
+    pub fn month_year(&self, first_of_month: NaiveDate) -> String {
+        format!("{} {}", self.month(first_of_month.month()), first_of_month.year())
+    }
+
+    /// "2 tedna" or "2 weeks"
+    pub fn weeks(&self, n: u32) -> String {
+        match self {
+            Lang::Sl => match n % 100 {
+                1 => format!("{n} teden"),
+                2 => format!("{n} tedna"),
+                3 | 4 => format!("{n} tedni"),
+                _ => format!("{n} tednov"),
+            },
+            Lang::En if n == 1 => "1 week".to_string(),
+            Lang::En => format!("{n} weeks"),
+        }
+    }
+
+    pub fn semester(&self, semester: u8) -> String {
+        format!("{semester}. semester")
+    }
+
+    /// "2. letnik" or "Year 2"
+    pub fn study_year(&self, year: u8) -> String {
+        match self {
+            Lang::Sl => format!("{year}. letnik"),
+            Lang::En => format!("Year {year}"),
+        }
+    }
+
+    pub fn level(&self, level: StudyLevel) -> &'static str {
+        match (self, level) {
+            (Lang::Sl, StudyLevel::BscProfessional) => "Visokošolski strokovni",
+            (Lang::Sl, StudyLevel::BscAcademic) => "Univerzitetni",
+            (Lang::Sl, StudyLevel::Msc) => "Magistrski",
+            (Lang::En, StudyLevel::BscProfessional) => "BSc (professional)",
+            (Lang::En, StudyLevel::BscAcademic) => "BSc (academic)",
+            (Lang::En, StudyLevel::Msc) => "MSc",
+        }
+    }
+
+    /// Short label for the entry box, e.g. "VP(S1, S2)" or "Tut(S1, S2)"
+    pub fn entry_type_short(&self, entry_type: &EntryType) -> String {
+        let (label, groups) = match (self, entry_type) {
+            (Lang::Sl, EntryType::Lecture) => ("P", None),
+            (Lang::Sl, EntryType::Exercise(ExerciseType::Tutorial(g))) => ("VP", Some(g)),
+            (Lang::Sl, EntryType::Exercise(ExerciseType::Lab(g))) => ("VL", Some(g)),
+            (Lang::En, EntryType::Lecture) => ("Lec", None),
+            (Lang::En, EntryType::Exercise(ExerciseType::Tutorial(g))) => ("Tut", Some(g)),
+            (Lang::En, EntryType::Exercise(ExerciseType::Lab(g))) => ("Lab", Some(g)),
+        };
+        match groups {
+            Some(groups) if !groups.is_empty() => format!("{label}({})", group_list(groups)),
+            _ => label.to_string(),
+        }
+    }
+
+    /// Full label, e.g. "Laboratorijske vaje (S1)" or "Lab (S1)"
+    pub fn entry_type(&self, entry_type: &EntryType) -> String {
+        let (label, groups) = match (self, entry_type) {
+            (Lang::Sl, EntryType::Lecture) => ("Predavanje", None),
+            (Lang::Sl, EntryType::Exercise(ExerciseType::Tutorial(g))) => ("Avditorne vaje", Some(g)),
+            (Lang::Sl, EntryType::Exercise(ExerciseType::Lab(g))) => ("Laboratorijske vaje", Some(g)),
+            (Lang::En, EntryType::Lecture) => ("Lecture", None),
+            (Lang::En, EntryType::Exercise(ExerciseType::Tutorial(g))) => ("Tutorial", Some(g)),
+            (Lang::En, EntryType::Exercise(ExerciseType::Lab(g))) => ("Lab", Some(g)),
+        };
+        match groups {
+            Some(groups) if !groups.is_empty() => format!("{label} ({})", group_list(groups)),
+            _ => label.to_string(),
+        }
+    }
+
+    /// Day view banner while a class is on
+    pub fn class_ends_in(&self, abbr: &str, minutes: i64) -> String {
+        match self {
+            Lang::Sl => format!("Zdaj: {abbr}, konec čez {minutes} min"),
+            Lang::En => format!("Now: {abbr}, ends in {minutes} min"),
+        }
+    }
+
+    /// Day view banner before the next class, `room` may be empty
+    pub fn next_class_in(&self, abbr: &str, room: &str, minutes: i64) -> String {
+        let duration = if minutes >= 60 {
+            format!("{}h {:02}min", minutes / 60, minutes % 60)
+        } else {
+            format!("{minutes} min")
+        };
+        match (self, room.is_empty()) {
+            (Lang::Sl, true) => format!("Naslednje čez {duration}: {abbr}"),
+            (Lang::Sl, false) => format!("Naslednje čez {duration}: {abbr} v {room}"),
+            (Lang::En, true) => format!("Next class in {duration}: {abbr}"),
+            (Lang::En, false) => format!("Next class in {duration}: {abbr} in {room}"),
+        }
+    }
+}
+
+fn group_list(groups: &[u32]) -> String {
+    groups.iter().map(|g| format!("S{g}")).collect::<Vec<_>>().join(", ")
+}
+
+/// Language of the surrounding `ContextProvider<Lang>`
+#[yew::hook]
+pub fn use_lang() -> Lang {
+    yew::use_context::<Lang>().unwrap_or(Lang::En)
+}
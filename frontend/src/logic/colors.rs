@@ -0,0 +1,211 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use shared::definitions::SubjectId;
+
+use crate::i18n::Text;
+use crate::utils::{hsl_to_rgb, Color};
+
+const PALETTE_KEY: &str = "timetable.palette";
+const OVERRIDES_KEY: &str = "timetable.subject_colors";
+const SLOTS_KEY: &str = "timetable.color_slots";
+
+/// Colors in each fixed palette
+const SLOT_COUNT: usize = 8;
+
+// Okabe & Ito, distinguishable with all common kinds of color blindness
+const COLOR_BLIND: [Color; SLOT_COUNT] = [
+    Color { r: 230, g: 159, b: 0 },
+    Color { r: 86, g: 180, b: 233 },
+    Color { r: 0, g: 158, b: 115 },
+    Color { r: 240, g: 228, b: 66 },
+    Color { r: 0, g: 114, b: 178 },
+    Color { r: 213, g: 94, b: 0 },
+    Color { r: 204, g: 121, b: 167 },
+    Color { r: 153, g: 153, b: 153 },
+];
+
+const HIGH_CONTRAST: [Color; SLOT_COUNT] = [
+    Color { r: 255, g: 221, b: 0 },
+    Color { r: 0, g: 68, b: 170 },
+    Color { r: 204, g: 0, b: 0 },
+    Color { r: 0, g: 136, b: 0 },
+    Color { r: 255, g: 255, b: 255 },
+    Color { r: 136, g: 0, b: 136 },
+    Color { r: 0, g: 204, b: 204 },
+    Color { r: 255, g: 128, b: 0 },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Palette {
+    #[default]
+    Default,
+    ColorBlind,
+    HighContrast,
+}
+
+impl Palette {
+    pub const ALL: [Palette; 3] = [Palette::Default, Palette::ColorBlind, Palette::HighContrast];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Palette::Default => "default",
+            Palette::ColorBlind => "color-blind",
+            Palette::HighContrast => "high-contrast",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Palette> {
+        Palette::ALL.into_iter().find(|p| p.code() == code)
+    }
+
+    pub fn name(&self) -> Text {
+        match self {
+            Palette::Default => Text::PaletteDefault,
+            Palette::ColorBlind => Text::PaletteColorBlind,
+            Palette::HighContrast => Text::PaletteHighContrast,
+        }
+    }
+
+    /// Colors handed out in order, `None` for the default palette which has no fixed set
+    fn slots(&self) -> Option<&'static [Color]> {
+        match self {
+            Palette::Default => None,
+            Palette::ColorBlind => Some(&COLOR_BLIND),
+            Palette::HighContrast => Some(&HIGH_CONTRAST),
+        }
+    }
+
+    /// Depends only on the subject, fixed palettes use it for subjects outside my basket
+    pub fn color(&self, id: SubjectId) -> Color {
+        match self {
+            // golden ratio steps keep neighbouring ids (subjects of one program) far apart on the wheel
+            Palette::Default => hsl_to_rgb((id.0 as f32 * 0.618_034).fract() * 360.0, 0.6, 0.55),
+            Palette::ColorBlind => COLOR_BLIND[id.0 as usize % COLOR_BLIND.len()].clone(),
+            Palette::HighContrast => HIGH_CONTRAST[id.0 as usize % HIGH_CONTRAST.len()].clone(),
+        }
+    }
+}
+
+/// Palette plus colors the user picked for single subjects, all kept in localStorage
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SubjectColors {
+    pub palette: Palette,
+    pub overrides: BTreeMap<SubjectId, Color>,
+    /// Fixed palette slot of each subject in my basket, kept so other subjects never recolor it
+    pub slots: BTreeMap<SubjectId, usize>,
+}
+
+impl SubjectColors {
+    pub fn load() -> SubjectColors {
+        SubjectColors {
+            palette: LocalStorage::get(PALETTE_KEY).unwrap_or_default(),
+            overrides: LocalStorage::get(OVERRIDES_KEY).unwrap_or_default(),
+            slots: LocalStorage::get(SLOTS_KEY).unwrap_or_default(),
+        }
+    }
+
+    pub fn get(&self, id: SubjectId) -> Color {
+        if let Some(color) = self.overrides.get(&id) {
+            return color.clone();
+        }
+        match (self.palette.slots(), self.slots.get(&id)) {
+            (Some(colors), Some(slot)) => colors[slot % colors.len()].clone(),
+            _ => self.palette.color(id),
+        }
+    }
+
+    /// Slots follow my basket: new subjects get the least used one, removed ones free theirs
+    pub fn with_basket(mut self, basket: &BTreeSet<SubjectId>) -> SubjectColors {
+        if assign_slots(&mut self.slots, basket, SLOT_COUNT) {
+            if let Err(err) = LocalStorage::set(SLOTS_KEY, &self.slots) {
+                web_sys::console::log_1(&format!("Could not save subject color slots: {err}").into());
+            }
+        }
+        self
+    }
+
+    pub fn with_palette(mut self, palette: Palette) -> SubjectColors {
+        self.palette = palette;
+        if let Err(err) = LocalStorage::set(PALETTE_KEY, palette) {
+            web_sys::console::log_1(&format!("Could not save the palette: {err}").into());
+        }
+        self
+    }
+
+    /// `None` goes back to the palette color
+    pub fn with_override(mut self, id: SubjectId, color: Option<Color>) -> SubjectColors {
+        match color {
+            Some(color) => self.overrides.insert(id, color),
+            None => self.overrides.remove(&id),
+        };
+        if let Err(err) = LocalStorage::set(OVERRIDES_KEY, &self.overrides) {
+            web_sys::console::log_1(&format!("Could not save subject colors: {err}").into());
+        }
+        self
+    }
+}
+
+/// Keeps the slots of subjects still in `basket`, true if anything changed. Past `count`
+/// subjects slots are shared, the least used one first
+fn assign_slots(slots: &mut BTreeMap<SubjectId, usize>, basket: &BTreeSet<SubjectId>, count: usize) -> bool {
+    let before = slots.len();
+    slots.retain(|id, _| basket.contains(id));
+    let mut changed = slots.len() != before;
+    for id in basket {
+        if slots.contains_key(id) {
+            continue;
+        }
+        let mut used = vec![0; count];
+        for slot in slots.values() {
+            used[slot % count] += 1;
+        }
+        let slot = (0..count).min_by_key(|slot| used[*slot]).unwrap_or(0);
+        slots.insert(*id, slot);
+        changed = true;
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(ids: &[u32]) -> BTreeSet<SubjectId> {
+        ids.iter().map(|id| SubjectId(*id)).collect()
+    }
+
+    #[test]
+    fn new_subjects_keep_existing_slots() {
+        let mut slots = BTreeMap::new();
+        assert!(assign_slots(&mut slots, &ids(&[30, 10, 20]), 8));
+        let before = slots.clone();
+
+        // lower and higher ids than the existing ones, more than the palette has
+        assert!(assign_slots(&mut slots, &ids(&[1, 5, 10, 20, 30, 40, 50, 60, 70, 80]), 8));
+        for (id, slot) in &before {
+            assert_eq!(slots[id], *slot);
+        }
+        assert!(!assign_slots(&mut slots, &ids(&[1, 5, 10, 20, 30, 40, 50, 60, 70, 80]), 8));
+    }
+
+    #[test]
+    fn up_to_count_subjects_get_different_slots() {
+        let mut slots = BTreeMap::new();
+        assign_slots(&mut slots, &ids(&[8, 7, 6, 5, 4, 3, 2, 1]), 8);
+        let used: BTreeSet<usize> = slots.values().copied().collect();
+        assert_eq!(used.len(), 8);
+    }
+
+    #[test]
+    fn removed_subject_frees_its_slot() {
+        let mut slots = BTreeMap::new();
+        assign_slots(&mut slots, &ids(&[1, 2, 3]), 8);
+        let freed = slots[&SubjectId(2)];
+        assign_slots(&mut slots, &ids(&[1, 3]), 8);
+        assign_slots(&mut slots, &ids(&[1, 3, 9]), 8);
+        assert_eq!(slots[&SubjectId(9)], freed);
+        assert_eq!(slots.len(), 3);
+    }
+}
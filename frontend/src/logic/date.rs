@@ -1,6 +1,25 @@
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 use js_sys::Date;
 
+/// Local date and time of the browser
+pub fn now() -> NaiveDateTime {
+    let now = Date::new_0();
+    let date = NaiveDate::from_ymd_opt(now.get_full_year() as i32, now.get_month() + 1, now.get_date())
+        .expect("valid date from JS");
+    let time = NaiveTime::from_hms_opt(now.get_hours(), now.get_minutes(), now.get_seconds())
+        .expect("valid time from JS");
+    date.and_time(time)
+}
+
+/// Next (or previous, with `step` -1) weekday, skipping Saturday and Sunday
+pub fn step_workday(date: NaiveDate, step: i64) -> NaiveDate {
+    let mut date = date + Duration::days(step);
+    while matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+        date += Duration::days(step);
+    }
+    date
+}
+
 pub fn current_iso_week() -> (i32, i32) {
     let now = Date::new_0();
     let year = now.get_full_year() as i32;
@@ -16,3 +35,28 @@ pub fn weeks_in_year(year: i32) -> i32 {
     NaiveDate::from_ymd_opt(year, 12, 28).unwrap().iso_week().week() as i32
 }
 
+/// First day of the month the ISO week mostly falls in (its Thursday)
+pub fn month_of_week(year: i32, week: i32) -> NaiveDate {
+    let thursday = NaiveDate::from_isoywd_opt(year, week as u32, chrono::Weekday::Thu).unwrap();
+    thursday.with_day(1).unwrap()
+}
+
+pub fn add_months(first_of_month: NaiveDate, months: i32) -> NaiveDate {
+    if months >= 0 {
+        first_of_month + Months::new(months as u32)
+    } else {
+        first_of_month - Months::new(months.unsigned_abs())
+    }
+}
+
+/// Mondays of the weeks shown in a month calendar
+pub fn month_mondays(first_of_month: NaiveDate) -> Vec<NaiveDate> {
+    let last = add_months(first_of_month, 1) - Duration::days(1);
+    let mut monday = first_of_month - Duration::days(first_of_month.weekday().num_days_from_monday() as i64);
+    let mut mondays = vec![];
+    while monday <= last {
+        mondays.push(monday);
+        monday += Duration::weeks(1);
+    }
+    mondays
+}
@@ -1,72 +1,147 @@
 use std::collections::HashMap;
-use chrono::{Timelike, Weekday};
-use shared::definitions::{EntryType, ExerciseType};
-use crate::{components::entry_box::EntryBoxProps, logic::visibility::SubjectsVisibilityMap};
+use chrono::NaiveDate;
+use shared::{db::{Database, Query}, definitions::{EntryType, ExerciseType, SubjectId}, layout, time::ranges_overlap};
+use crate::components::entry_box::{entry_to_props, EntryBoxProps};
+use crate::logic::overrides::{Overrides, Series};
+use crate::logic::personal::{personal_props_between, personal_props_for_week, PersonalEvent};
+use crate::logic::view::View;
+use crate::logic::visibility::{basket, SubjectsVisibilityMap};
+use crate::utils::Color;
 
-pub fn filter_entries(
-    entries: Vec<EntryBoxProps>,
-    visibility_map: &SubjectsVisibilityMap,
-) -> Vec<EntryBoxProps> {
-    entries.into_iter().filter(|entry| {
-        visibility_map.get(&entry.subject_id).is_some_and(|v| {
-            if !v.can_show {
-                return false;
-            }
-
-            match &entry.entry_type {
-                EntryType::Lecture => true,
-                EntryType::Exercise(ex_type) => match ex_type {
-                    ExerciseType::Tutorial(groups) =>
-                        groups.iter().any(|gid| v.tutorial_groups.get(gid).copied().unwrap_or(false)),
-                    ExerciseType::Lab(groups) =>
-                        groups.iter().any(|gid| v.lab_groups.get(gid).copied().unwrap_or(false)),
-                },
-            }
-        })
-
-    }).collect()
+/// Everything that decides which entries are shown and in which color
+pub struct EntrySource<'a> {
+    pub db: &'a Database,
+    pub view: View,
+    pub visibility_map: &'a SubjectsVisibilityMap,
+    pub overrides: &'a Overrides,
+    pub colors: &'a HashMap<SubjectId, Color>,
+    pub personal_events: &'a [PersonalEvent],
 }
 
-pub fn arrange_columns(entries: Vec<EntryBoxProps>) -> Vec<EntryBoxProps> {
-    let mut lessons_by_day: HashMap<Weekday, Vec<EntryBoxProps>> = HashMap::new();
-    for entry in entries {
-        lessons_by_day.entry(entry.day).or_default().push(entry);
+impl EntrySource<'_> {
+    /// Entries of one ISO week, personal events included in the program view
+    pub fn week(&self, year: i32, week: i32) -> Vec<EntryBoxProps> {
+        let mut entries = self.timetable_entries(Some(week));
+        if self.view == View::Program {
+            entries.extend(personal_props_for_week(self.personal_events, year, week));
+        }
+        entries
     }
 
-    let mut final_entries = Vec::new();
-    for (_day, day_lessons) in lessons_by_day {
-        let mut columns: Vec<Vec<EntryBoxProps>> = Vec::new();
+    /// Entries between `from` and `to` inclusive
+    pub fn between(&self, from: NaiveDate, to: NaiveDate) -> Vec<EntryBoxProps> {
+        let mut entries = self.timetable_entries(None);
+        entries.retain(|e| (from..=to).contains(&e.date));
+        if self.view == View::Program {
+            entries.extend(personal_props_between(self.personal_events, from, to));
+        }
+        entries
+    }
 
-        for lesson in day_lessons {
-            let start = lesson.start_time.hour() * 60 + lesson.start_time.minute();
-            let end = start + lesson.duration.hour() * 60 + lesson.duration.minute();
+    fn timetable_entries(&self, week: Option<i32>) -> Vec<EntryBoxProps> {
+        let db = self.db;
+        let mut entries = match &self.view {
+            View::Program => {
+                let basket = basket(self.visibility_map);
+                let entries = in_week(db.query().subjects(&basket), week)
+                    .run()
+                    .into_iter()
+                    .map(|e| entry_to_props(db, e))
+                    .collect::<Vec<_>>();
 
-            let mut placed = false;
-            for col in columns.iter_mut() {
-                if col.iter().all(|l| {
-                    let l_start = l.start_time.hour() * 60 + l.start_time.minute();
-                    let l_end = l_start + l.duration.hour() * 60 + l.duration.minute();
-                    end <= l_start || start >= l_end
-                }) {
-                    col.push(lesson.clone());
-                    placed = true;
-                    break;
-                }
+                filter_entries(entries, self.visibility_map, self.overrides)
+            }
+            View::Teacher(id) => {
+                in_week(db.query().teacher(id), week).run().into_iter().map(|e| entry_to_props(db, e)).collect()
+            }
+            View::Classroom(id) => {
+                in_week(db.query().classroom(id), week).run().into_iter().map(|e| entry_to_props(db, e)).collect()
             }
-            if !placed {
-                columns.push(vec![lesson.clone()]);
+        };
+        for entry in entries.iter_mut() {
+            if let Some(color) = self.colors.get(&entry.subject_id) {
+                entry.color = color.clone();
             }
         }
+        entries
+    }
+}
 
-        let num_columns = columns.len();
-        for (col_idx, col) in columns.iter().enumerate() {
-            for lesson in col {
-                let mut l = lesson.clone();
-                l.offset_x_multiplier = col_idx as f32;
-                l.total_columns = num_columns;
-                final_entries.push(l);
-            }
+fn in_week(query: Query<'_>, week: Option<i32>) -> Query<'_> {
+    match week {
+        Some(week) => query.week(week),
+        None => query,
+    }
+}
+
+pub fn is_visible(
+    visibility_map: &SubjectsVisibilityMap,
+    subject_id: &SubjectId,
+    entry_type: &EntryType,
+) -> bool {
+    visibility_map.get(subject_id).is_some_and(|v| {
+        if !v.can_show {
+            return false;
         }
+
+        match entry_type {
+            EntryType::Lecture => true,
+            EntryType::Exercise(ex_type) => match ex_type {
+                ExerciseType::Tutorial(groups) =>
+                    groups.iter().any(|gid| v.tutorial_groups.get(gid).copied().unwrap_or(false)),
+                ExerciseType::Lab(groups) =>
+                    groups.iter().any(|gid| v.lab_groups.get(gid).copied().unwrap_or(false)),
+            },
+        }
+    })
+}
+
+/// Subject and group visibility, then my overrides of single entries
+pub fn filter_entries(
+    entries: Vec<EntryBoxProps>,
+    visibility_map: &SubjectsVisibilityMap,
+    overrides: &Overrides,
+) -> Vec<EntryBoxProps> {
+    entries
+        .into_iter()
+        .filter(|entry| {
+            let visible = is_visible(visibility_map, &entry.subject_id, &entry.entry_type);
+            match entry.entry_id {
+                Some(id) => overrides.apply(&id, &Series::new(entry.subject_id, &entry.entry_type, entry.date, entry.start_time), visible),
+                None => visible,
+            }
+        })
+        .collect()
+}
+
+pub fn arrange_columns(entries: Vec<EntryBoxProps>) -> Vec<EntryBoxProps> {
+    let placements = layout::arrange_columns(&entries);
+    entries
+        .into_iter()
+        .zip(placements)
+        .map(|(mut entry, placement)| {
+            entry.offset_x_multiplier = placement.column as f32;
+            entry.total_columns = placement.total_columns;
+            entry
+        })
+        .collect()
+}
+
+/// Flags entries that overlap another entry on the same date
+pub fn mark_clashes(mut entries: Vec<EntryBoxProps>) -> Vec<EntryBoxProps> {
+    let clashes: Vec<bool> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            entries.iter().enumerate().any(|(j, b)| {
+                i != j
+                    && a.date == b.date
+                    && ranges_overlap((a.start_time, a.end_time), (b.start_time, b.end_time))
+            })
+        })
+        .collect();
+    for (entry, clash) in entries.iter_mut().zip(clashes) {
+        entry.clash = clash;
     }
-    final_entries
+    entries
 }
@@ -1,4 +1,10 @@
 pub mod date;
 pub mod visibility;
 pub mod entries;
-pub mod bindings;
\ No newline at end of file
+pub mod personal;
+pub mod view;
+pub mod saved;
+pub mod colors;
+pub mod theme;
+pub mod profile;
+pub mod overrides;
\ No newline at end of file
@@ -0,0 +1,150 @@
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use shared::db::Database;
+use shared::definitions::{Entry, EntryId, EntryType, ExerciseType, SubjectId};
+
+use crate::logic::entries::is_visible;
+use crate::logic::visibility::SubjectsVisibilityMap;
+
+const STORAGE_KEY: &str = "timetable.overrides";
+
+/// The same slot every week: subject, type with groups, weekday and start time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Series {
+    pub subject_id: SubjectId,
+    pub entry_type: EntryType,
+    pub weekday: Weekday,
+    pub start_time: NaiveTime,
+}
+
+impl Series {
+    pub fn new(subject_id: SubjectId, entry_type: &EntryType, date: NaiveDate, start_time: NaiveTime) -> Series {
+        Series { subject_id, entry_type: entry_type.clone(), weekday: date.weekday(), start_time }
+    }
+
+    pub fn of(entry: &Entry) -> Series {
+        Series::new(entry.subject_id, &entry.entry_type, entry.date, entry.start_time)
+    }
+}
+
+/// Exceptions to subject and group visibility for single entries, e.g. a lab swapped with
+/// another group for one week or a skipped lecture
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Overrides {
+    pub hidden: BTreeSet<EntryId>,
+    pub shown: BTreeSet<EntryId>,
+    pub hidden_series: Vec<Series>,
+}
+
+/// One override, listed so it can be undone
+#[derive(Debug, Clone, PartialEq)]
+pub enum OverrideItem {
+    Hidden(EntryId),
+    Shown(EntryId),
+    HiddenSeries(Series),
+}
+
+/// What the entry detail asks for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OverrideAction {
+    Hide,
+    HideSeries,
+    SwapTo(EntryId),
+    Reset,
+}
+
+impl Overrides {
+    pub fn load() -> Overrides {
+        LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(STORAGE_KEY, self);
+    }
+
+    /// `visible` is what subject and group visibility say
+    pub fn apply(&self, id: &EntryId, series: &Series, visible: bool) -> bool {
+        if self.shown.contains(id) {
+            true
+        } else if self.hidden.contains(id) || self.hidden_series.contains(series) {
+            false
+        } else {
+            visible
+        }
+    }
+
+    pub fn is_overridden(&self, id: &EntryId, series: &Series) -> bool {
+        self.shown.contains(id) || self.hidden.contains(id) || self.hidden_series.contains(series)
+    }
+
+    pub fn items(&self) -> Vec<OverrideItem> {
+        self.hidden.iter().map(|id| OverrideItem::Hidden(*id))
+            .chain(self.shown.iter().map(|id| OverrideItem::Shown(*id)))
+            .chain(self.hidden_series.iter().cloned().map(OverrideItem::HiddenSeries))
+            .collect()
+    }
+
+    pub fn without(mut self, item: &OverrideItem) -> Overrides {
+        match item {
+            OverrideItem::Hidden(id) => { self.hidden.remove(id); }
+            OverrideItem::Shown(id) => { self.shown.remove(id); }
+            OverrideItem::HiddenSeries(series) => self.hidden_series.retain(|s| s != series),
+        }
+        self
+    }
+
+    pub fn with(mut self, entry: &Entry, action: OverrideAction) -> Overrides {
+        match action {
+            OverrideAction::Hide => {
+                self.shown.remove(&entry.id);
+                self.hidden.insert(entry.id);
+            }
+            OverrideAction::HideSeries => {
+                let series = Series::of(entry);
+                if !self.hidden_series.contains(&series) {
+                    self.hidden_series.push(series);
+                }
+            }
+            OverrideAction::SwapTo(other) => {
+                self.shown.remove(&entry.id);
+                self.hidden.insert(entry.id);
+                self.hidden.remove(&other);
+                self.shown.insert(other);
+            }
+            OverrideAction::Reset => {
+                let series = Series::of(entry);
+                self.hidden.remove(&entry.id);
+                self.shown.remove(&entry.id);
+                self.hidden_series.retain(|s| *s != series);
+            }
+        }
+        self
+    }
+}
+
+/// Shown after subject and group visibility and my overrides
+pub fn is_shown(visibility_map: &SubjectsVisibilityMap, overrides: &Overrides, entry: &Entry) -> bool {
+    let visible = is_visible(visibility_map, &entry.subject_id, &entry.entry_type);
+    overrides.apply(&entry.id, &Series::of(entry), visible)
+}
+
+/// Other groups' tutorials or labs of the subject in the same week, to swap into
+pub fn alternatives<'a>(db: &'a Database, visibility_map: &SubjectsVisibilityMap, overrides: &Overrides, entry: &Entry) -> Vec<&'a Entry> {
+    let same_kind = |other: &EntryType| matches!(
+        (&entry.entry_type, other),
+        (EntryType::Exercise(ExerciseType::Tutorial(_)), EntryType::Exercise(ExerciseType::Tutorial(_)))
+            | (EntryType::Exercise(ExerciseType::Lab(_)), EntryType::Exercise(ExerciseType::Lab(_)))
+    );
+    let iso = entry.date.iso_week();
+    let mut alternatives: Vec<&Entry> = db.entries()
+        .iter()
+        .filter(|e| e.subject_id == entry.subject_id && e.entry_type != entry.entry_type && same_kind(&e.entry_type))
+        .filter(|e| e.date.iso_week() == iso)
+        .filter(|e| !is_shown(visibility_map, overrides, e))
+        .collect();
+    alternatives.sort_by_key(|e| e.start_datetime());
+    alternatives
+}
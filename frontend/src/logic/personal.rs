@@ -0,0 +1,145 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Timelike, Weekday};
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use shared::definitions::{EntryType, Minutes, SubjectId};
+use shared::ics::Event;
+use shared::time::{DAY_END, DAY_START};
+
+use crate::components::entry_box::EntryBoxProps;
+use crate::i18n::Text;
+use crate::utils::Color;
+
+const STORAGE_KEY: &str = "timetable.personal_events";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Repeat {
+    Weekly(Weekday),
+    Once(NaiveDate),
+}
+
+/// User defined event (job, sports practice, ...) that isn't on urnik.fs.uni-lj.si
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersonalEvent {
+    pub id: u32,
+    pub title: String,
+    pub repeat: Repeat,
+    pub start_time: NaiveTime,
+    pub duration: Minutes,
+    pub color: Color,
+}
+
+impl PersonalEvent {
+    pub fn end_time(&self) -> NaiveTime {
+        self.start_time + self.duration.to_duration()
+    }
+
+    /// Checks the event fits in the Mon-Fri 7:00-20:00 grid
+    pub fn validate(&self) -> Result<(), Text> {
+        if self.title.trim().is_empty() {
+            return Err(Text::TitleEmpty);
+        }
+        let weekday = match &self.repeat {
+            Repeat::Weekly(day) => *day,
+            Repeat::Once(date) => date.weekday(),
+        };
+        if matches!(weekday, Weekday::Sat | Weekday::Sun) {
+            return Err(Text::WeekdaysOnly);
+        }
+        let start = Minutes::from_hm(self.start_time.hour(), self.start_time.minute());
+        if self.duration.0 == 0 || start.0 < DAY_START || start.0 + self.duration.0 > DAY_END {
+            return Err(Text::OutsideHours);
+        }
+        Ok(())
+    }
+
+    /// Dates of the event in the given ISO week
+    pub fn dates_in_week(&self, year: i32, week: i32) -> Vec<NaiveDate> {
+        match &self.repeat {
+            Repeat::Weekly(day) => NaiveDate::from_isoywd_opt(year, week as u32, *day).into_iter().collect(),
+            Repeat::Once(date) => {
+                let iso = date.iso_week();
+                if iso.year() == year && iso.week() as i32 == week {
+                    vec![*date]
+                } else {
+                    vec![]
+                }
+            }
+        }
+    }
+
+    /// Dates of the event between `from` and `to` inclusive
+    pub fn dates_between(&self, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        match &self.repeat {
+            Repeat::Weekly(day) => {
+                let offset = (day.num_days_from_monday() as i64 - from.weekday().num_days_from_monday() as i64).rem_euclid(7);
+                let mut dates = vec![];
+                let mut date = from + Duration::days(offset);
+                while date <= to {
+                    dates.push(date);
+                    date += Duration::weeks(1);
+                }
+                dates
+            }
+            Repeat::Once(date) => (from..=to).contains(date).then_some(*date).into_iter().collect(),
+        }
+    }
+
+    pub fn to_props(&self, date: NaiveDate) -> EntryBoxProps {
+        EntryBoxProps {
+            entry_id: None,
+            subject_id: SubjectId(0),
+            subject_name: self.title.clone(),
+            subject_abbr: self.title.clone(),
+            entry_type: EntryType::Lecture,
+            teachers: vec![],
+            classroom: String::new(),
+            day: date.weekday(),
+            date,
+            start_time: self.start_time,
+            duration: self.duration,
+            end_time: self.end_time(),
+            offset_x_multiplier: 0.0,
+            total_columns: 1,
+            color: self.color.clone(),
+            personal: true,
+            clash: false,
+        }
+    }
+
+    pub fn to_event(&self, date: NaiveDate) -> Event {
+        let start = date.and_time(self.start_time);
+        Event {
+            uid: format!("personal-{}-{}@timetable", self.id, date.format("%Y%m%d")),
+            start,
+            end: start + self.duration.to_duration(),
+            summary: self.title.clone(),
+            location: None,
+            description: None,
+            url: None,
+        }
+    }
+}
+
+pub fn load_personal_events() -> Vec<PersonalEvent> {
+    LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+}
+
+pub fn save_personal_events(events: &[PersonalEvent]) {
+    if let Err(err) = LocalStorage::set(STORAGE_KEY, events) {
+        web_sys::console::log_1(&format!("Could not save personal events: {err}").into());
+    }
+}
+
+pub fn personal_props_for_week(events: &[PersonalEvent], year: i32, week: i32) -> Vec<EntryBoxProps> {
+    events
+        .iter()
+        .flat_map(|event| event.dates_in_week(year, week).into_iter().map(move |date| event.to_props(date)))
+        .collect()
+}
+
+pub fn personal_props_between(events: &[PersonalEvent], from: NaiveDate, to: NaiveDate) -> Vec<EntryBoxProps> {
+    events
+        .iter()
+        .flat_map(|event| event.dates_between(from, to).into_iter().map(move |date| event.to_props(date)))
+        .collect()
+}
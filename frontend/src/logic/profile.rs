@@ -0,0 +1,22 @@
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+const STORAGE_KEY: &str = "timetable.profile";
+
+/// My tutorial (VP) and lab (VL) group, the only ones switched on when a subject is added.
+/// None keeps all groups on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct Profile {
+    pub tutorial_group: Option<u32>,
+    pub lab_group: Option<u32>,
+}
+
+impl Profile {
+    pub fn load() -> Profile {
+        LocalStorage::get(STORAGE_KEY).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let _ = LocalStorage::set(STORAGE_KEY, self);
+    }
+}
@@ -0,0 +1,56 @@
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use shared::definitions::{MainProgramId, Semester};
+use shared::selection::{GroupSelection, Selection};
+
+use crate::logic::visibility::{shown_groups, SubjectsVisibilityMap};
+
+const STORAGE_KEY: &str = "timetable.selection";
+
+/// Program, track, semester and shown subjects with their groups, restored on the next visit (also offline)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedSelection {
+    pub main_program: MainProgramId,
+    #[serde(default)]
+    pub track: Option<String>,
+    pub semester: Semester,
+    pub selection: Selection,
+}
+
+impl SavedSelection {
+    pub fn new(main_program: MainProgramId, track: Option<String>, semester: Semester, map: &SubjectsVisibilityMap) -> SavedSelection {
+        let subjects = map
+            .iter()
+            .filter(|(_, v)| v.can_show)
+            .map(|(id, v)| {
+                (*id, GroupSelection { tutorial_groups: shown_groups(&v.tutorial_groups), lab_groups: shown_groups(&v.lab_groups) })
+            })
+            .collect();
+        SavedSelection { main_program, track, semester, selection: Selection { subjects } }
+    }
+
+    /// Shows the saved subjects and groups, subjects that no longer exist are skipped
+    pub fn apply(&self, map: &mut SubjectsVisibilityMap) {
+        for (id, groups) in &self.selection.subjects {
+            if let Some(v) = map.get_mut(id) {
+                v.can_show = true;
+                for (g, show) in v.tutorial_groups.iter_mut() {
+                    *show = groups.tutorial_groups.contains(g);
+                }
+                for (g, show) in v.lab_groups.iter_mut() {
+                    *show = groups.lab_groups.contains(g);
+                }
+            }
+        }
+    }
+}
+
+pub fn load_selection() -> Option<SavedSelection> {
+    LocalStorage::get(STORAGE_KEY).ok()
+}
+
+pub fn save_selection(selection: &SavedSelection) {
+    if let Err(err) = LocalStorage::set(STORAGE_KEY, selection) {
+        web_sys::console::log_1(&format!("Could not save selection: {err}").into());
+    }
+}
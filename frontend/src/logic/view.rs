@@ -0,0 +1,21 @@
+use shared::definitions::{ClassroomId, TeacherId};
+
+/// What the week timetable shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    /// Selected subjects of the main program and semester
+    Program,
+    /// Everything a teacher teaches
+    Teacher(TeacherId),
+    /// Everything held in a classroom
+    Classroom(ClassroomId),
+}
+
+/// How much time the timetable shows at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayMode {
+    Day,
+    Week,
+    Month,
+    Semester,
+}
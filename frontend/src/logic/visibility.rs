@@ -1,7 +1,8 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use shared::definitions::{Subject, SubjectId};
 use crate::components::subject_select_container::GroupType;
+use crate::logic::profile::Profile;
 
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -48,15 +49,50 @@ impl SubjectVisibility {
             *group = visible;
         }
     }
+
+    /// Only my groups on, all of a type when the subject doesn't have my group of it
+    pub fn apply_profile(&mut self, profile: &Profile) {
+        pick_group(&mut self.tutorial_groups, profile.tutorial_group);
+        pick_group(&mut self.lab_groups, profile.lab_group);
+    }
+}
+
+fn pick_group(groups: &mut BTreeMap<u32, bool>, group: Option<u32>) {
+    let mine = group.filter(|g| groups.contains_key(g));
+    for (g, show) in groups.iter_mut() {
+        *show = mine.is_none_or(|mine| mine == *g);
+    }
+}
+
+/// Ids of the groups that are switched on
+pub fn shown_groups(groups: &BTreeMap<u32, bool>) -> BTreeSet<u32> {
+    groups.iter().filter(|(_, show)| **show).map(|(g, _)| *g).collect()
 }
 
 // Central visibility map
 pub type SubjectsVisibilityMap = BTreeMap<SubjectId, SubjectVisibility>;
 
+/// "My subjects": every switched on subject, from any program
+pub fn basket(map: &SubjectsVisibilityMap) -> BTreeSet<SubjectId> {
+    map.iter().filter(|(_, v)| v.can_show).map(|(id, _)| *id).collect()
+}
+
 
-pub fn toggle_subject(mut map: SubjectsVisibilityMap, subject_id: SubjectId) -> SubjectsVisibilityMap {
+/// A subject switched on starts with the groups of my profile
+pub fn toggle_subject(mut map: SubjectsVisibilityMap, subject_id: SubjectId, profile: &Profile) -> SubjectsVisibilityMap {
     if let Some(v) = map.get_mut(&subject_id) {
         v.can_show = !v.can_show;
+        if v.can_show {
+            v.apply_profile(profile);
+        }
+    }
+    map
+}
+
+/// Picks the groups of a changed profile in all my subjects
+pub fn apply_profile(mut map: SubjectsVisibilityMap, profile: &Profile) -> SubjectsVisibilityMap {
+    for v in map.values_mut().filter(|v| v.can_show) {
+        v.apply_profile(profile);
     }
     map
 }
@@ -3,6 +3,9 @@ pub mod components;
 pub mod utils;
 pub mod logic;
 pub mod database;
+pub mod i18n;
+pub mod export;
+pub mod pwa;
 
 use components::app::App;
 
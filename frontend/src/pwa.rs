@@ -0,0 +1,36 @@
+//! Offline support: service worker registration (frontend/sw.js) and the check for newer timetable data.
+
+use gloo::net::http::Request;
+use shared::bundle::DataManifest;
+use web_sys::RequestCache;
+
+use crate::database::FrontendDatabase;
+
+pub fn register_service_worker() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    // missing on plain http
+    if !js_sys::Reflect::has(&window.navigator(), &"serviceWorker".into()).unwrap_or(false) {
+        return;
+    }
+    let _ = window.navigator().service_worker().register("sw.js");
+}
+
+pub fn is_online() -> bool {
+    web_sys::window().map(|w| w.navigator().on_line()).unwrap_or(false)
+}
+
+/// True when the published data manifest names a different bundle than the one compiled in
+pub async fn newer_data_available() -> bool {
+    if !is_online() {
+        return false;
+    }
+    let Ok(response) = Request::get("data_manifest.json").cache(RequestCache::NoStore).send().await else {
+        return false;
+    };
+    match response.json::<DataManifest>().await {
+        Ok(manifest) => manifest.fingerprint != FrontendDatabase::fingerprint(),
+        Err(_) => false,
+    }
+}
@@ -1,26 +1,54 @@
-#[derive(Debug, PartialEq, Clone)]
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlAnchorElement;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
     pub b: u8,
 }
 impl Color {
+    pub const BLACK: Color = Color { r: 0, g: 0, b: 0 };
+    pub const WHITE: Color = Color { r: 255, g: 255, b: 255 };
+
     pub fn css(&self) -> String {
         format!("rgb({}, {}, {})", self.r, self.g, self.b)
     }
-}
 
-pub fn create_multiple_colors(n: usize) -> Vec<Color> {
-    (0..n)
-        .map(|i| {
-            let hue = (i as f32 / n as f32) * 360.0;
-            hsl_to_rgb(hue, 0.6, 0.55)
-        })
-        .collect()
+    /// "#rrggbb", as used by `<input type="color">`
+    pub fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let hex = hex.strip_prefix('#')?;
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        Some(Color { r: channel(0)?, g: channel(2)?, b: channel(4)? })
+    }
+
+    /// WCAG relative luminance, 0 for black and 1 for white
+    pub fn luminance(&self) -> f32 {
+        let linear = |c: u8| {
+            let c = c as f32 / 255.0;
+            if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// Black or white, whichever has the higher contrast ratio on this background
+    pub fn text_color(&self) -> Color {
+        let l = self.luminance();
+        // (l + 0.05) / 0.05 against black vs 1.05 / (l + 0.05) against white
+        if (l + 0.05) * (l + 0.05) >= 0.05 * 1.05 { Color::BLACK } else { Color::WHITE }
+    }
 }
 
 // Converts HSL to RGB
-fn hsl_to_rgb(h: f32, s: f32, l: f32) -> Color {
+pub fn hsl_to_rgb(h: f32, s: f32, l: f32) -> Color {
     let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
     let h_prime = h / 60.0;
     let x = c * (1.0 - ((h_prime % 2.0) - 1.0).abs());
@@ -39,3 +67,47 @@ fn hsl_to_rgb(h: f32, s: f32, l: f32) -> Color {
         b: ((b1 + m) * 255.0).round() as u8,
     }
 }
+
+/// Triggers a browser download of `href` (data URL) as `filename`
+pub fn download(filename: &str, href: &str) {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let link: HtmlAnchorElement = document.create_element("a").unwrap().dyn_into().unwrap();
+    link.set_href(href);
+    link.set_download(filename);
+    link.click();
+}
+
+/// Triggers a browser download of text content
+pub fn download_text(filename: &str, mime: &str, content: &str) {
+    let encoded = js_sys::encode_uri_component(content);
+    download(filename, &format!("data:{mime};charset=utf-8,{}", String::from(encoded)));
+}
+
+/// Phones and narrow windows get the day view instead of the five column week
+pub fn is_narrow_screen() -> bool {
+    web_sys::window()
+        .and_then(|w| w.inner_width().ok())
+        .and_then(|w| w.as_f64())
+        .is_some_and(|w| w < 700.0)
+}
+
+/// Triggers a browser download of binary content, e.g. a PDF
+pub fn download_bytes(filename: &str, mime: &str, bytes: &[u8]) {
+    download(filename, &format!("data:{mime};base64,{}", base64(bytes)));
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,95 @@
+// Service worker: keeps the app shell, wasm (with the data bundle inside) and styles
+// cached so the timetable opens without a connection.
+//
+// - pages: network first, cached copy when offline
+// - data_manifest.json: always from the network, the app compares it to its own bundle
+// - everything else (hashed by trunk): cache first
+
+const CACHE = "timetable-v1";
+const SHELL = ["./", "./index.html", "./manifest.webmanifest", "./icon.svg"];
+
+self.addEventListener("install", (event) => {
+    event.waitUntil((async () => {
+        const cache = await caches.open(CACHE);
+        await cache.addAll(SHELL);
+        await cacheAssetsOf(cache, await cache.match("./"));
+        await self.skipWaiting();
+    })());
+});
+
+self.addEventListener("activate", (event) => {
+    event.waitUntil((async () => {
+        for (const key of await caches.keys()) {
+            if (key !== CACHE) {
+                await caches.delete(key);
+            }
+        }
+        await self.clients.claim();
+    })());
+});
+
+self.addEventListener("fetch", (event) => {
+    const request = event.request;
+    const url = new URL(request.url);
+    if (request.method !== "GET" || url.origin !== self.location.origin) {
+        return;
+    }
+
+    if (url.pathname.endsWith("/data_manifest.json")) {
+        event.respondWith(fetch(request, { cache: "no-store" }));
+    } else if (request.mode === "navigate") {
+        event.respondWith(networkFirst(request));
+    } else {
+        event.respondWith(cacheFirst(request));
+    }
+});
+
+async function networkFirst(request) {
+    const cache = await caches.open(CACHE);
+    try {
+        const response = await fetch(request);
+        if (response.ok) {
+            await cache.put("./", response.clone());
+            await cacheAssetsOf(cache, response.clone());
+        }
+        return response;
+    } catch (err) {
+        return (await cache.match("./")) || Response.error();
+    }
+}
+
+async function cacheFirst(request) {
+    const cache = await caches.open(CACHE);
+    const cached = await cache.match(request);
+    if (cached) {
+        return cached;
+    }
+    const response = await fetch(request);
+    if (response.ok) {
+        await cache.put(request, response.clone());
+    }
+    return response;
+}
+
+// Caches the js, wasm and css an index.html links to and drops the ones of older builds
+async function cacheAssetsOf(cache, page) {
+    if (!page) {
+        return;
+    }
+    const html = await page.text();
+    const assets = [...html.matchAll(/(?:href|src)="([^"]+\.(?:js|wasm|css))"/g)]
+        .map((match) => new URL(match[1], self.registration.scope).href);
+    const missing = [];
+    for (const asset of assets) {
+        if (!(await cache.match(asset))) {
+            missing.push(asset);
+        }
+    }
+    await cache.addAll(missing);
+
+    for (const request of await cache.keys()) {
+        if (/\.(js|wasm|css)$/.test(request.url) && !assets.includes(request.url)) {
+            await cache.delete(request);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::fs::File;
+use std::io::Write;
+use std::time::Instant;
+use anyhow::Result;
+
+use shared::bundle::DataManifest;
+use shared::{data::load_database_from_json, db::Database};
+
+const JSON_FILES: [&str; 8] = [
+    "static/main_programs.json",
+    "static/programs.json",
+    "static/semesters.json",
+    "static/subjects.json",
+    "static/teachers.json",
+    "static/classrooms.json",
+    "static/work_free_days.json",
+    "static/entries.json",
+];
+
+/// Encodes static/*.json into static/timetable.bin (with static/data_manifest.json) and compares size and load time
+pub fn write_and_report() -> Result<()> {
+    let start = Instant::now();
+    let db = load_database_from_json()?;
+    let json_load = start.elapsed();
+
+    let bytes = db.to_bundle()?;
+    write_bundle("static/timetable.bin", &bytes)?;
+    let manifest = DataManifest::new(&bytes, chrono::Local::now().to_rfc3339());
+    std::fs::write("static/data_manifest.json", serde_json::to_string_pretty(&manifest)?)?;
+
+    let start = Instant::now();
+    let decoded = Database::from_bundle(&bytes)?;
+    let bundle_load = start.elapsed();
+
+    if decoded.entries() != db.entries() {
+        eprintln!("Warning: bundle entries differ from JSON entries");
+    }
+
+    let json_size: u64 = JSON_FILES
+        .iter()
+        .map(|path| std::fs::metadata(path).map(|m| m.len()))
+        .sum::<std::io::Result<u64>>()?;
+
+    println!("JSON:   {json_size:>9} bytes, loaded in {json_load:?}");
+    println!("Bundle: {:>9} bytes, loaded in {bundle_load:?}", bytes.len());
+
+    Ok(())
+}
+
+pub fn write_bundle(file_path: &str, bytes: &[u8]) -> std::io::Result<()> {
+    let mut file = File::create(file_path)?;
+    file.write_all(bytes)?;
+
+    Ok(())
+}
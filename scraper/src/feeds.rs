@@ -0,0 +1,88 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use anyhow::Result;
+use serde::Serialize;
+
+use shared::{db::Database, definitions::{ClassroomId, Entry, MainProgramId, ProgramId, TeacherId}, ics::calendar_for_entries, time::semester_of};
+
+/// Listed in feeds/index.json so the site can link to the feeds
+#[derive(Serialize)]
+struct Feed {
+    kind: &'static str,
+    name: String,
+    file: String,
+    events: usize,
+}
+
+/// Writes one ICS file per main program + semester, program, teacher and classroom.
+/// Event UIDs come from entry ids, so subscribed calendars update in place after a re-scrape.
+pub fn write_feeds(db: &Database, dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+
+    // BTreeMaps so feeds are written in a stable order
+    let mut by_main_program: BTreeMap<(u32, u8), Vec<&Entry>> = BTreeMap::new();
+    let mut by_program: BTreeMap<u32, Vec<&Entry>> = BTreeMap::new();
+    let mut by_teacher: BTreeMap<u32, Vec<&Entry>> = BTreeMap::new();
+    let mut by_classroom: BTreeMap<u32, Vec<&Entry>> = BTreeMap::new();
+
+    let mut entries: Vec<&Entry> = db.entries().iter().collect();
+    entries.sort_by_key(|e| (e.start_datetime(), e.id));
+
+    for entry in entries {
+        if let Some(subject) = db.subjects.get(&entry.subject_id) {
+            // a subject taught in both semesters only goes into the feed of the entry's one
+            let semester = semester_of(entry.date);
+            let offerings = subject.offerings.iter().filter(|o| semester.is_none_or(|s| s == o.semester));
+            let mut program_ids = BTreeSet::new();
+            for offering in offerings {
+                by_main_program.entry((offering.main_program_id.0, offering.semester.0)).or_default().push(entry);
+                program_ids.extend(offering.program_ids.iter().map(|p| p.0));
+            }
+            for program_id in program_ids {
+                by_program.entry(program_id).or_default().push(entry);
+            }
+        }
+        for teacher_id in &entry.teacher_ids {
+            by_teacher.entry(teacher_id.0).or_default().push(entry);
+        }
+        by_classroom.entry(entry.classroom_id.0).or_default().push(entry);
+    }
+
+    let mut index = Vec::new();
+    let mut write = |kind: &'static str, name: String, file: String, entries: &[&Entry]| -> Result<()> {
+        let ics = calendar_for_entries(db, &name, entries);
+        File::create(dir.join(&file))?.write_all(ics.as_bytes())?;
+        index.push(Feed { kind, name, file, events: entries.len() });
+        Ok(())
+    };
+
+    for ((main_program_id, semester), entries) in &by_main_program {
+        let Some(mp) = db.main_programs.get(&MainProgramId(*main_program_id)) else {
+            continue;
+        };
+        let name = format!("{} · {}. semester", mp.name, semester);
+        write("main_program", name, format!("main-program-{}-semester-{semester}.ics", mp.name), entries)?;
+    }
+    for (id, entries) in &by_program {
+        let name = db.programs.get(&ProgramId(*id)).map(|p| p.full_name.clone()).unwrap_or_else(|| format!("Program {id}"));
+        write("program", name, format!("program-{id}.ics"), entries)?;
+    }
+    for (id, entries) in &by_teacher {
+        let name = db.teachers.get(&TeacherId(*id)).map(|t| t.name.clone()).unwrap_or_else(|| format!("Teacher {id}"));
+        write("teacher", name, format!("teacher-{id}.ics"), entries)?;
+    }
+    for (id, entries) in &by_classroom {
+        let name = db.classrooms.get(&ClassroomId(*id)).map(|c| c.full_name.clone()).unwrap_or_else(|| format!("Classroom {id}"));
+        write("classroom", name, format!("classroom-{id}.ics"), entries)?;
+    }
+
+    let json_string = serde_json::to_string_pretty(&index)
+        .expect("Failed to serialize feed index to JSON");
+    File::create(dir.join("index.json"))?.write_all(json_string.as_bytes())?;
+
+    println!("Wrote {} calendar feeds to {}", index.len(), dir.display());
+
+    Ok(())
+}
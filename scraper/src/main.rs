@@ -1,36 +1,17 @@
 use std::fs::File;
 use std::io::Write;
-use chrono::NaiveDate;
-use anyhow::Result;
+use std::path::Path;
+use anyhow::{Result, anyhow};
 
-use shared::definitions::{ProgramId, Semester};
+use shared::{csv::{Column, CsvWriter}, data::load_database_from_json, db::Database, definitions::{Entry, ProgramId, Semester}, time::get_semesters};
 
 pub mod programs_teachers_classrooms;
 pub mod work_free_days;
 pub mod subjects_entries;
+pub mod bundle;
+pub mod feeds;
 
 
-pub fn get_semesters() -> Vec<Semester> {
-    vec![
-        Semester(1),
-        Semester(2),
-    ]
-}
-
-pub fn get_semester_data(semester: &Semester) -> (NaiveDate, NaiveDate) {
-    match semester {
-        Semester(1) => {(
-            NaiveDate::parse_from_str("2025-10-01", "%Y-%m-%d").unwrap(),
-            NaiveDate::parse_from_str("2026-01-26", "%Y-%m-%d").unwrap(),
-        )},
-        Semester(2) => {(
-            NaiveDate::parse_from_str("2026-02-09", "%Y-%m-%d").unwrap(),
-            NaiveDate::parse_from_str("2026-06-01", "%Y-%m-%d").unwrap(),
-        )},
-        _ => panic!("Invalid semester {semester:?}"),
-    }
-}
-
 /// Valid program ids because some on website are outdated
 pub fn get_valid_program_ids() -> Vec<ProgramId> {
     let valid_ids: Vec<u32> = vec![
@@ -48,6 +29,24 @@ pub fn get_valid_program_ids() -> Vec<ProgramId> {
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    match std::env::args().nth(1).as_deref() {
+        None | Some("scrape") => scrape().await,
+        Some("bundle") => bundle::write_and_report(),
+        Some("sqlite") => {
+            let path = std::env::args().nth(2).unwrap_or_else(|| "static/timetable.sqlite".to_string());
+            write_sqlite(&path)
+        }
+        Some("csv") => {
+            let path = std::env::args().nth(2).unwrap_or_else(|| "static/entries.csv".to_string());
+            let columns = std::env::args().nth(3);
+            write_csv(&path, columns.as_deref())
+        }
+        Some("feeds") => feeds::write_feeds(&load_database_from_json()?, Path::new("static/feeds")),
+        Some(command) => Err(anyhow!("Unknown command '{command}', expected one of: scrape, bundle, sqlite, feeds, csv")),
+    }
+}
+
+async fn scrape() -> Result<()> {
     let start = std::time::Instant::now();
 
     let semesters = get_semesters();
@@ -56,6 +55,8 @@ async fn main() -> Result<()> {
     work_free_days::get_and_write(&semesters).await?;
     programs_teachers_classrooms::get_and_write().await?;
     subjects_entries::get_and_write(&semesters).await?;
+    bundle::write_and_report()?;
+    feeds::write_feeds(&load_database_from_json()?, Path::new("static/feeds"))?;
 
     let duration = start.elapsed();
     println!("✅ Finished in {duration:?}");
@@ -76,3 +77,39 @@ pub fn write_semesters_json(file_path: &str, semesters: &[Semester]) -> std::io:
 
     Ok(())
 }
+
+/// Exports static/*.json into a SQLite file for ad-hoc queries
+pub fn write_sqlite(file_path: &str) -> Result<()> {
+    let db = load_database_from_json()?;
+    db.to_sqlite(file_path)?;
+
+    // Make sure the export can be read back
+    let loaded = Database::from_sqlite(file_path)?;
+    println!(
+        "Wrote {file_path}: {} subjects, {} entries, {} teachers, {} classrooms",
+        loaded.subjects.len(),
+        loaded.entries().len(),
+        loaded.teachers.len(),
+        loaded.classrooms.len(),
+    );
+
+    Ok(())
+}
+
+/// Exports all entries from static/*.json as CSV, optionally only the given columns ("date,start,subject_abbr")
+pub fn write_csv(file_path: &str, columns: Option<&str>) -> Result<()> {
+    let db = load_database_from_json()?;
+    let mut entries: Vec<&Entry> = db.query().run();
+    entries.sort_by_key(|e| (e.start_datetime(), e.subject_id));
+
+    let mut writer = CsvWriter::new();
+    if let Some(columns) = columns {
+        writer = writer.columns(&Column::parse_list(columns)?);
+    }
+
+    let mut file = File::create(file_path)?;
+    file.write_all(writer.write(&db, &entries).as_bytes())?;
+    println!("Wrote {} entries to {file_path}", entries.len());
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ use anyhow::Result;
 use reqwest::Client;
 
 use shared::definitions::{Classroom, ClassroomId, MainProgram, MainProgramId, Program, ProgramId, Teacher, TeacherId};
+use shared::programs::{ProgramLabel, ProgramParseError};
 
 use crate::get_valid_program_ids;
 
@@ -45,14 +46,17 @@ pub async fn get_and_write() -> Result<()> {
                     if !get_valid_program_ids().contains(&ProgramId(index)) {
                         continue;
                     }
-                    handle_program_main(
+                    // one odd label shouldn't stop the whole scrape
+                    if let Err(err) = handle_program_main(
                         rest,
                         index,
                         &url,
                         &mut main_programs,
                         &mut main_program_id,
                         &mut programs,
-                    );
+                    ) {
+                        eprintln!("Warning: skipping program {index}: {err}");
+                    }
                 } else if let Some(rest) = label.strip_prefix("Učitelj: ") {
                     teachers.push(Teacher {
                         name: rest.to_string(),
@@ -81,58 +85,29 @@ pub async fn get_and_write() -> Result<()> {
 
 
 
-fn handle_program_main(rest: &str, index: u32, url: &str, main_programs: &mut HashMap<String, MainProgram>, main_program_id_counter: &mut u32, programs: &mut Vec<Program>) {
-    // Save full name
-    let full_name = rest.to_string();
-
-    // Extract all parentheses
-    let parens = rest.match_indices('(')
-        .map(|(start, _)| start)
-        .collect::<Vec<_>>();
-
-    let (name, main_program_abbr, abbr) = if parens.len() >= 2 {
-        // first '(' index
-        let first_start = parens[0];
-        let second_start = parens[1];
-
-        let name = rest[..first_start].trim().to_string(); // everything before first '('
-        let main_program_abbr = &rest[first_start+1..rest[first_start..].find(')').unwrap_or_else(|| panic!("Can't find first ')' in {rest}")) + first_start]; // content in first ()
-        
-        // second parentheses
-        let second_end = rest[second_start..].find(')').unwrap_or_else(|| panic!("Can't find second ')' in {rest}")) + second_start;
-        let abbr = &rest[second_start+1..second_end]; // content in second ()
-
-        (name, main_program_abbr, abbr)
-    } else {
-        (rest.to_string(), "UNKNOWN", "UNKNOWN")
-    };
+fn handle_program_main(rest: &str, index: u32, url: &str, main_programs: &mut HashMap<String, MainProgram>, main_program_id_counter: &mut u32, programs: &mut Vec<Program>) -> Result<(), ProgramParseError> {
+    // e.g. "Procesno strojništvo (MAG-2) (PCS-MAG)"
+    let label = ProgramLabel::parse(rest)?;
+    // parsed before anything is inserted, so a skipped program leaves nothing behind
+    let mut program = Program::new(ProgramId(index), rest.to_string(), url.to_string(), MainProgramId(0))?;
 
     // Get or insert MainProgramId
-    let main_program_id = match main_programs.entry(main_program_abbr.to_string()) {
+    let main_program_id = match main_programs.entry(label.main_program.clone()) {
         std::collections::hash_map::Entry::Occupied(mut entry) => {
             entry.get_mut().program_ids.insert(ProgramId(index));
             entry.get().id
         }
         std::collections::hash_map::Entry::Vacant(entry) => {
             let id = MainProgramId(*main_program_id_counter);
-            entry.insert(MainProgram {
-                id,
-                name: main_program_abbr.to_string(),
-                program_ids: BTreeSet::from([ProgramId(index)]),
-            });
+            entry.insert(MainProgram::new(id, label.main_program, BTreeSet::from([ProgramId(index)]))?);
             *main_program_id_counter += 1;
             id
         }
     };
 
-    programs.push(Program {
-        id: ProgramId(index),
-        full_name,
-        name,
-        abbr: abbr.to_string(),
-        url: url.to_string(),
-        main_program_id,
-    });
+    program.main_program_id = main_program_id;
+    programs.push(program);
+    Ok(())
 }
 
 
@@ -5,9 +5,9 @@ use chrono::{Datelike, Days, Duration, NaiveDate, NaiveTime, Weekday};
 use reqwest::Client;
 use scraper::{Html, Selector}; // other scraper crate
 
-use shared::{data::{load_main_programs_from_json, load_programs_from_json}, definitions::{ClassroomId, Entry, EntryType, ExerciseType, MainProgramId, ProgramId, Semester, Subject, SubjectId, TeacherId}, time::{DURATION_MAP, START_TIME_MAP}};
+use shared::{data::{load_main_programs_from_json, load_programs_from_json}, definitions::{ClassroomId, Entry, EntryId, EntryType, ExerciseType, MainProgramId, Minutes, Offering, Program, ProgramId, Semester, Subject, SubjectId, TeacherId}, time::{DURATION_MAP, START_TIME_MAP}};
 
-use crate::get_semester_data;
+use shared::time::get_semester_data;
 
 
 pub async fn get_and_write(semesters: &Vec<Semester>) -> Result<()> {
@@ -44,6 +44,7 @@ pub async fn get_and_write(semesters: &Vec<Semester>) -> Result<()> {
                     let _ = handle_subjects_entries(
                         &html_str,
                         semester,
+                        &programs,
                         &mut subjects,
                         &mut entries,
                     );
@@ -52,6 +53,10 @@ pub async fn get_and_write(semesters: &Vec<Semester>) -> Result<()> {
         }
     }
 
+    // Same lecture is listed on every program page it belongs to
+    let (entries, folded) = merge_entries(entries);
+    println!("Folded {folded} duplicate entries, {} unique entries left", entries.len());
+
     // Write JSON outputs
     write_subjects_json("static/subjects.json", &subjects)?;
     write_entries_json("static/entries.json", &entries)?;
@@ -60,7 +65,7 @@ pub async fn get_and_write(semesters: &Vec<Semester>) -> Result<()> {
 }
 
 
-fn handle_subjects_entries(html_entry_str: &str, semester: &Semester, subjects: &mut HashMap<SubjectId, Subject>, entries: &mut Vec<Entry>) -> Result<()> {
+fn handle_subjects_entries(html_entry_str: &str, semester: &Semester, programs: &HashMap<ProgramId, Program>, subjects: &mut HashMap<SubjectId, Subject>, entries: &mut Vec<Entry>) -> Result<()> {
     let fragment = Html::parse_fragment(html_entry_str);
 
     let entry_type = get_entry_type(&fragment)?;
@@ -68,9 +73,12 @@ fn handle_subjects_entries(html_entry_str: &str, semester: &Semester, subjects:
     let classroom_id = get_classroom_id(&fragment)?;
     let (date, start_time, duration) = get_date_start_time_duration(&fragment)?;
     let (subject_id, _subject_full, subject_name, subject_abbr, subject_url) = get_subject_data(&fragment)?;
-    let (program_ids, main_program_id) = get_main_programs(&fragment)?;
+    let offerings = get_offerings(&fragment, programs, *semester)?;
     match subjects.entry(subject_id) {
         std::collections::hash_map::Entry::Occupied(mut entry) => {
+            for offering in offerings {
+                entry.get_mut().add_offering(offering);
+            }
             match entry_type.clone() {
                 EntryType::Lecture => {},
                 EntryType::Exercise(type_) => {
@@ -91,22 +99,24 @@ fn handle_subjects_entries(html_entry_str: &str, semester: &Semester, subjects:
                     }
                 }
             };
-            let subject = Subject {
+            let mut subject = Subject {
                 id: subject_id,
-                program_ids,
-                main_program_id,
-                semester: *semester,
+                offerings: vec![],
                 name: subject_name,
                 abbr: subject_abbr,
                 url: subject_url,
                 tutorial_groups: tut,
                 lab_groups: lab,
             };
+            for offering in offerings {
+                subject.add_offering(offering);
+            }
             subjects.insert(subject_id, subject);
         }
     };
 
     let entry = Entry {
+        id: EntryId::new(subject_id, &entry_type, date, start_time, classroom_id),
         subject_id,
         entry_type,
         teacher_ids,
@@ -122,6 +132,40 @@ fn handle_subjects_entries(html_entry_str: &str, semester: &Semester, subjects:
 
 }
 
+/// Combines entries with the same id into one, keeping the order of first occurrence.
+/// Teachers are unioned in case pages list them differently and groups are sorted like
+/// in the id. Returns number of folded entries.
+pub fn merge_entries(entries: Vec<Entry>) -> (Vec<Entry>, usize) {
+    let mut merged: Vec<Entry> = Vec::with_capacity(entries.len());
+    let mut index_by_id: HashMap<EntryId, usize> = HashMap::new();
+    let mut folded = 0;
+
+    for entry in entries {
+        match index_by_id.get(&entry.id) {
+            Some(&i) => {
+                merged[i].teacher_ids.extend(entry.teacher_ids);
+                folded += 1;
+            }
+            None => {
+                let mut entry = entry;
+                normalize_groups(&mut entry.entry_type);
+                index_by_id.insert(entry.id, merged.len());
+                merged.push(entry);
+            }
+        }
+    }
+
+    (merged, folded)
+}
+
+// "S2, S1" on one page and "S1, S2" on another are the same session
+fn normalize_groups(entry_type: &mut EntryType) {
+    if let EntryType::Exercise(ExerciseType::Tutorial(groups) | ExerciseType::Lab(groups)) = entry_type {
+        groups.sort_unstable();
+        groups.dedup();
+    }
+}
+
 /// Gets all monday dates d1-6days to d2
 pub fn get_mondays_from_to(d1: NaiveDate, d2: NaiveDate) -> Result<Vec<NaiveDate>> {
     let mut mondays = Vec::new();
@@ -148,7 +192,7 @@ pub fn get_mondays_from_to(d1: NaiveDate, d2: NaiveDate) -> Result<Vec<NaiveDate
 }
 
 
-pub fn get_date_start_time_duration(fragment: &Html) -> Result<(NaiveDate, NaiveTime, NaiveTime)> {
+pub fn get_date_start_time_duration(fragment: &Html) -> Result<(NaiveDate, NaiveTime, Minutes)> {
     // Parse the entry div
     let style_selector = Selector::parse("div.entry-absolute-box")
         .map_err(|e| anyhow!("Selector parse error: {e:?}"))?;
@@ -163,7 +207,7 @@ pub fn get_date_start_time_duration(fragment: &Html) -> Result<(NaiveDate, Naive
         .ok_or_else(|| anyhow!("No style attribute found"))?;
 
     let mut start_time: Option<NaiveTime> = None;
-    let mut duration: Option<NaiveTime> = None;
+    let mut duration: Option<Minutes> = None;
     let mut day_offset: Option<u32> = None;
 
     for kv in style.split(';') {
@@ -361,7 +405,9 @@ fn get_subject_data(fragment: &Html) -> Result<(SubjectId, String, String, Strin
     Ok((id, subject_full, subject_name, subject_abbr, subject_url))
 }
 
-fn get_main_programs(fragment: &Html) -> Result<(BTreeSet<ProgramId>, MainProgramId)> {
+/// One offering per main program the entry's program links belong to.
+/// Programs missing from programs.json go under the main program named in the first link's title.
+fn get_offerings(fragment: &Html, programs: &HashMap<ProgramId, Program>, semester: Semester) -> Result<Vec<Offering>> {
     let selector = Selector::parse("span.layer_one a")
         .map_err(|e| anyhow!("Failed to parse selector: {e:?}"))?;
 
@@ -375,6 +421,28 @@ fn get_main_programs(fragment: &Html) -> Result<(BTreeSet<ProgramId>, MainProgra
         })
         .collect();
 
+    let mut by_main_program: HashMap<MainProgramId, BTreeSet<ProgramId>> = HashMap::new();
+    let mut unknown = BTreeSet::new();
+    for program_id in program_ids {
+        match programs.get(&program_id) {
+            Some(program) => { by_main_program.entry(program.main_program_id).or_default().insert(program_id); }
+            None => { unknown.insert(program_id); }
+        }
+    }
+    if !unknown.is_empty() || by_main_program.is_empty() {
+        by_main_program.entry(get_title_main_program(fragment)?).or_default().extend(unknown);
+    }
+
+    Ok(by_main_program
+        .into_iter()
+        .map(|(main_program_id, program_ids)| Offering { main_program_id, program_ids, semester })
+        .collect())
+}
+
+fn get_title_main_program(fragment: &Html) -> Result<MainProgramId> {
+    let selector = Selector::parse("span.layer_one a")
+        .map_err(|e| anyhow!("Failed to parse selector: {e:?}"))?;
+
     // Extract group from the first <a> only
     let group = fragment
         .select(&selector)
@@ -395,7 +463,7 @@ fn get_main_programs(fragment: &Html) -> Result<(BTreeSet<ProgramId>, MainProgra
         .map(|mp| mp.1.id)
         .ok_or_else(|| anyhow!("No main program with matching name found: {group}"))?;
 
-    Ok((program_ids, main_program_id))
+    Ok(main_program_id)
 }
 
 
@@ -422,3 +490,66 @@ pub fn write_entries_json(file_path: &str, entries: &Vec<Entry>) -> std::io::Res
 
     Ok(())
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(entry_type: EntryType, teachers: &[u32], classroom: u32, start: &str) -> Entry {
+        let date = NaiveDate::from_ymd_opt(2025, 10, 6).unwrap();
+        let start_time = NaiveTime::parse_from_str(start, "%H:%M").unwrap();
+        Entry {
+            id: EntryId::new(SubjectId(1), &entry_type, date, start_time, ClassroomId(classroom)),
+            subject_id: SubjectId(1),
+            entry_type,
+            teacher_ids: teachers.iter().map(|t| TeacherId(*t)).collect(),
+            classroom_id: ClassroomId(classroom),
+            date,
+            week_iso: date.iso_week().week() as i32,
+            start_time,
+            duration: Minutes(90),
+        }
+    }
+
+    #[test]
+    fn duplicates_union_their_teachers() {
+        let (merged, folded) = merge_entries(vec![
+            entry(EntryType::Lecture, &[7], 10, "08:00"),
+            entry(EntryType::Lecture, &[3], 10, "08:00"),
+            entry(EntryType::Lecture, &[7, 12], 10, "08:00"),
+        ]);
+        assert_eq!(folded, 2);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].teacher_ids, HashSet::from([TeacherId(3), TeacherId(7), TeacherId(12)]));
+    }
+
+    #[test]
+    fn groups_in_any_order_are_one_entry() {
+        let tutorial = |groups: Vec<u32>| EntryType::Exercise(ExerciseType::Tutorial(groups));
+        let (merged, folded) = merge_entries(vec![
+            entry(tutorial(vec![2, 1]), &[7], 10, "10:00"),
+            entry(tutorial(vec![1, 2, 2]), &[7], 10, "10:00"),
+        ]);
+        assert_eq!(folded, 1);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].entry_type, tutorial(vec![1, 2]));
+    }
+
+    #[test]
+    fn different_sessions_stay_separate() {
+        let entries = vec![
+            entry(EntryType::Lecture, &[7], 10, "08:00"),
+            // another classroom, start time, type or group
+            entry(EntryType::Lecture, &[7], 11, "08:00"),
+            entry(EntryType::Lecture, &[7], 10, "10:00"),
+            entry(EntryType::Exercise(ExerciseType::Tutorial(vec![1])), &[7], 10, "08:00"),
+            entry(EntryType::Exercise(ExerciseType::Tutorial(vec![2])), &[7], 10, "08:00"),
+            entry(EntryType::Exercise(ExerciseType::Lab(vec![1])), &[7], 10, "08:00"),
+        ];
+        let (merged, folded) = merge_entries(entries.clone());
+        assert_eq!(folded, 0);
+        assert_eq!(merged, entries);
+    }
+}
+
@@ -6,7 +6,9 @@ use anyhow::Result;
 use chrono::{Days, NaiveDate};
 use shared::definitions::Semester;
 
-use crate::{get_semester_data, subjects_entries::get_mondays_from_to};
+use shared::time::get_semester_data;
+
+use crate::subjects_entries::get_mondays_from_to;
 
 pub async fn get_and_write(semesters: &Vec<Semester>) -> Result<()> {
     let client = Client::builder()
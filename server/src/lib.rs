@@ -0,0 +1,80 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+use axum::{routing::get, Router};
+
+use shared::{data::load_database, db::Database};
+
+pub mod error;
+pub mod routes;
+
+
+/// Shared handle to the currently loaded database, swapped on reload
+#[derive(Clone)]
+pub struct AppState {
+    db: Arc<RwLock<Arc<Database>>>,
+}
+
+impl AppState {
+    pub fn new(db: Database) -> Self {
+        AppState { db: Arc::new(RwLock::new(Arc::new(db))) }
+    }
+
+    pub fn db(&self) -> Arc<Database> {
+        self.db.read().expect("database lock poisoned").clone()
+    }
+
+    pub fn replace(&self, db: Database) {
+        *self.db.write().expect("database lock poisoned") = Arc::new(db);
+    }
+}
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/api/main-programs", get(routes::main_programs))
+        .route("/api/programs", get(routes::programs))
+        .route("/api/semesters", get(routes::semesters))
+        .route("/api/subjects", get(routes::subjects))
+        .route("/api/teachers", get(routes::teachers))
+        .route("/api/classrooms", get(routes::classrooms))
+        .route("/api/work-free-days", get(routes::work_free_days))
+        .route("/api/entries", get(routes::entries))
+        .route("/api/entries/{id}", get(routes::entry))
+        .route("/api/free-rooms", get(routes::free_rooms))
+        .route("/api/calendar/{code}", get(routes::calendar))
+        .fallback(routes::not_found)
+        .with_state(state)
+}
+
+/// Latest modification time of the files in `dir`
+fn last_modified(dir: &Path) -> Option<SystemTime> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok()?.metadata().ok()?.modified().ok())
+        .max()
+}
+
+/// Polls `dir` and reloads the database when a file in it changes (e.g. after a re-scrape)
+pub async fn watch_and_reload(state: AppState, dir: PathBuf, interval: Duration) {
+    let mut seen = last_modified(&dir);
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        let modified = last_modified(&dir);
+        if modified == seen {
+            continue;
+        }
+        seen = modified;
+
+        let load_dir = dir.clone();
+        match tokio::task::spawn_blocking(move || load_database(&load_dir)).await {
+            Ok(Ok(db)) => {
+                println!("Reloaded data from {}: {} entries", dir.display(), db.entries().len());
+                state.replace(db);
+            }
+            // Files may be half written while the scraper runs, try again on the next change
+            Ok(Err(e)) => eprintln!("Reload of {} failed: {e}", dir.display()),
+            Err(e) => eprintln!("Reload task failed: {e}"),
+        }
+    }
+}
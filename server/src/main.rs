@@ -0,0 +1,38 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+use anyhow::{Result, anyhow};
+use clap::Parser;
+
+use shared::data::load_database;
+use timetable_server::{router, watch_and_reload, AppState};
+
+
+/// Serve the timetable data over HTTP
+#[derive(Parser)]
+#[command(name = "timetable-server")]
+struct Args {
+    /// Directory with timetable.bin or *.json data, watched for changes
+    #[arg(long, default_value = "static")]
+    data: PathBuf,
+
+    #[arg(long, default_value = "127.0.0.1:8000")]
+    addr: SocketAddr,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+    let db = load_database(&args.data)
+        .map_err(|e| anyhow!("Cannot load data from {}: {e}", args.data.display()))?;
+    println!("Loaded {} entries from {}", db.entries().len(), args.data.display());
+
+    let state = AppState::new(db);
+    tokio::spawn(watch_and_reload(state.clone(), args.data.clone(), Duration::from_secs(2)));
+
+    let listener = tokio::net::TcpListener::bind(args.addr).await?;
+    println!("Listening on http://{}", args.addr);
+    axum::serve(listener, router(state)).await?;
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use axum::{
+    extract::{rejection::{PathRejection, QueryRejection}, Path, Query, State},
+    http::header,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{NaiveDate, NaiveTime};
+use serde::Deserialize;
+
+use shared::{
+    definitions::{Classroom, ClassroomId, Entry, EntryId, MainProgram, MainProgramId, Program, ProgramId, Semester, Subject, SubjectId, Teacher, TeacherId},
+    ics::calendar_for_entries,
+    selection::Selection,
+    time::ranges_overlap,
+};
+
+use crate::{error::ApiError, AppState};
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+fn sorted<T, K: Ord>(items: impl Iterator<Item = T>, key: impl Fn(&T) -> K) -> Vec<T> {
+    let mut items: Vec<T> = items.collect();
+    items.sort_by_key(key);
+    items
+}
+
+pub async fn main_programs(State(state): State<AppState>) -> ApiResult<Vec<MainProgram>> {
+    Ok(Json(sorted(state.db().main_programs.values().cloned(), |mp| mp.id.0)))
+}
+
+pub async fn programs(State(state): State<AppState>) -> ApiResult<Vec<Program>> {
+    Ok(Json(sorted(state.db().programs.values().cloned(), |p| p.id)))
+}
+
+pub async fn semesters(State(state): State<AppState>) -> ApiResult<Vec<Semester>> {
+    Ok(Json(state.db().semesters.clone()))
+}
+
+#[derive(Deserialize)]
+pub struct SubjectParams {
+    main_program: Option<u32>,
+    semester: Option<u8>,
+}
+
+pub async fn subjects(
+    State(state): State<AppState>,
+    params: Result<Query<SubjectParams>, QueryRejection>,
+) -> ApiResult<Vec<Subject>> {
+    let Query(params) = params?;
+    let db = state.db();
+    // Both filters have to match the same offering
+    let subjects = db.subjects.values().filter(|s| {
+        s.offerings.iter().any(|o| {
+            params.main_program.is_none_or(|id| o.main_program_id == MainProgramId(id))
+                && params.semester.is_none_or(|sem| o.semester == Semester(sem))
+        })
+    });
+    Ok(Json(sorted(subjects.cloned(), |s| s.id)))
+}
+
+pub async fn teachers(State(state): State<AppState>) -> ApiResult<Vec<Teacher>> {
+    Ok(Json(sorted(state.db().teachers.values().cloned(), |t| t.id.0)))
+}
+
+pub async fn classrooms(State(state): State<AppState>) -> ApiResult<Vec<Classroom>> {
+    Ok(Json(sorted(state.db().classrooms.values().cloned(), |c| c.id.0)))
+}
+
+pub async fn work_free_days(State(state): State<AppState>) -> ApiResult<Vec<NaiveDate>> {
+    Ok(Json(state.db().work_free_days.clone()))
+}
+
+/// Every `Query` option, plus an optional selection code (see [`Selection::to_code`])
+#[derive(Deserialize, Default)]
+pub struct EntryParams {
+    main_program: Option<u32>,
+    program: Option<u32>,
+    subject: Option<u32>,
+    teacher: Option<u32>,
+    classroom: Option<u32>,
+    semester: Option<u8>,
+    date: Option<NaiveDate>,
+    week: Option<i32>,
+    year: Option<i32>,
+    selection: Option<String>,
+}
+
+fn query_entries(db: &shared::db::Database, params: &EntryParams) -> Result<Vec<Entry>, ApiError> {
+    let main_program = params.main_program.map(MainProgramId);
+    let program = params.program.map(ProgramId);
+    let subject = params.subject.map(SubjectId);
+    let teacher = params.teacher.map(TeacherId);
+    let classroom = params.classroom.map(ClassroomId);
+    let selection = params.selection
+        .as_deref()
+        .map(Selection::from_code)
+        .transpose()
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+
+    let mut query = db.query();
+    if let Some(id) = &main_program {
+        query = query.main_program(id);
+    }
+    if let Some(id) = &program {
+        query = query.program(id);
+    }
+    if let Some(id) = &subject {
+        query = query.subject(id);
+    }
+    if let Some(id) = &teacher {
+        query = query.teacher(id);
+    }
+    if let Some(id) = &classroom {
+        query = query.classroom(id);
+    }
+    if let Some(sem) = params.semester {
+        query = query.semester(Semester(sem));
+    }
+    if let Some(date) = params.date {
+        query = query.date(date);
+    }
+    if let Some(week) = params.week {
+        query = query.week(week);
+    }
+    if let Some(year) = params.year {
+        query = query.year(year);
+    }
+
+    let mut entries: Vec<Entry> = query
+        .run()
+        .into_iter()
+        .filter(|e| selection.as_ref().is_none_or(|s| s.contains(e)))
+        .cloned()
+        .collect();
+    entries.sort_by_key(|e| (e.start_datetime(), e.id));
+    Ok(entries)
+}
+
+pub async fn entries(
+    State(state): State<AppState>,
+    params: Result<Query<EntryParams>, QueryRejection>,
+) -> ApiResult<Vec<Entry>> {
+    let Query(params) = params?;
+    Ok(Json(query_entries(&state.db(), &params)?))
+}
+
+pub async fn entry(
+    State(state): State<AppState>,
+    id: Result<Path<String>, PathRejection>,
+) -> ApiResult<Entry> {
+    let Path(id) = id?;
+    let id: EntryId = id.parse().map_err(|_| ApiError::bad_request(format!("Invalid entry id '{id}'")))?;
+    state.db()
+        .entry(&id)
+        .cloned()
+        .map(Json)
+        .ok_or_else(|| ApiError::not_found(format!("No entry with id {id}")))
+}
+
+#[derive(Deserialize)]
+pub struct FreeRoomParams {
+    date: NaiveDate,
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+/// Classrooms without any entry overlapping `start..end` on `date`
+pub async fn free_rooms(
+    State(state): State<AppState>,
+    params: Result<Query<FreeRoomParams>, QueryRejection>,
+) -> ApiResult<Vec<Classroom>> {
+    let Query(params) = params?;
+    if params.start >= params.end {
+        return Err(ApiError::bad_request("start must be before end"));
+    }
+
+    let db = state.db();
+    let busy: std::collections::HashSet<ClassroomId> = db.query()
+        .date(params.date)
+        .run()
+        .into_iter()
+        .filter(|e| ranges_overlap((e.start_time, e.end_time()), (params.start, params.end)))
+        .map(|e| e.classroom_id)
+        .collect();
+
+    let free = db.classrooms.values().filter(|c| !busy.contains(&c.id));
+    Ok(Json(sorted(free.cloned(), |c| c.full_name.clone())))
+}
+
+/// ICS feed of a selection, e.g. `/api/calendar/533.t1.l1-579`
+pub async fn calendar(
+    State(state): State<AppState>,
+    code: Result<Path<String>, PathRejection>,
+) -> Result<impl IntoResponse, ApiError> {
+    let Path(code) = code?;
+    let code = code.strip_suffix(".ics").unwrap_or(&code).to_string();
+    let db = state.db();
+    let params = EntryParams { selection: Some(code), ..Default::default() };
+    let entries = query_entries(&db, &params)?;
+    let refs: Vec<&Entry> = entries.iter().collect();
+    let body = calendar_for_entries(&db, "Timetable", &refs);
+    Ok(([(header::CONTENT_TYPE, "text/calendar; charset=utf-8")], body))
+}
+
+pub async fn not_found() -> ApiError {
+    ApiError::not_found("No such endpoint")
+}
@@ -0,0 +1,202 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use axum::{body::Body, http::{Request, StatusCode}, Router};
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use http_body_util::BodyExt;
+use serde_json::Value;
+use tower::ServiceExt;
+
+use shared::{db::Database, definitions::{Classroom, ClassroomId, Entry, EntryId, EntryType, ExerciseType, MainProgram, MainProgramId, Minutes, Offering, Program, ProgramId, Semester, Subject, SubjectId, Teacher, TeacherId}};
+use timetable_server::{router, AppState};
+
+fn entry(subject: u32, entry_type: EntryType, classroom: u32, date: &str, start: &str, minutes: u32) -> Entry {
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+    let start_time = NaiveTime::parse_from_str(start, "%H:%M").unwrap();
+    Entry {
+        id: EntryId::new(SubjectId(subject), &entry_type, date, start_time, ClassroomId(classroom)),
+        subject_id: SubjectId(subject),
+        entry_type,
+        teacher_ids: HashSet::from([TeacherId(7)]),
+        classroom_id: ClassroomId(classroom),
+        date,
+        week_iso: date.iso_week().week() as i32,
+        start_time,
+        duration: Minutes(minutes),
+    }
+}
+
+fn test_db() -> Database {
+    let subject = |id: u32, abbr: &str, semester: u8| Subject {
+        id: SubjectId(id),
+        offerings: vec![Offering {
+            main_program_id: MainProgramId(0),
+            program_ids: BTreeSet::from([ProgramId(85)]),
+            semester: Semester(semester),
+        }],
+        name: format!("Subject {abbr}"),
+        abbr: abbr.to_string(),
+        url: format!("/subject/{id}/"),
+        tutorial_groups: BTreeSet::from([1, 2]),
+        lab_groups: BTreeSet::new(),
+    };
+
+    Database::new(
+        HashMap::from([(
+            MainProgramId(0),
+            MainProgram::new(MainProgramId(0), "MAG-1".into(), BTreeSet::from([ProgramId(85)])).unwrap(),
+        )]),
+        HashMap::from([(
+            ProgramId(85),
+            Program::new(ProgramId(85), "Energetsko strojništvo (MAG-1) (ES-MAG)".into(), "/layer_one/85/".into(), MainProgramId(0)).unwrap(),
+        )]),
+        vec![Semester(1), Semester(2)],
+        HashMap::from([
+            (SubjectId(1), subject(1, "TDZM", 1)),
+            (SubjectId(2), subject(2, "MLTR", 2)),
+        ]),
+        HashMap::from([(TeacherId(7), Teacher { id: TeacherId(7), name: "Ana Novak".into(), url: "/teacher/7/".into() })]),
+        HashMap::from([
+            (ClassroomId(10), Classroom { id: ClassroomId(10), full_name: "Predavalnica I/1".into(), url: "/classroom/10/".into() }),
+            (ClassroomId(11), Classroom { id: ClassroomId(11), full_name: "Predavalnica I/2".into(), url: "/classroom/11/".into() }),
+        ]),
+        vec![NaiveDate::from_ymd_opt(2025, 10, 31).unwrap()],
+        vec![
+            entry(1, EntryType::Lecture, 10, "2025-10-06", "08:00", 120),
+            entry(1, EntryType::Exercise(ExerciseType::Tutorial(vec![1])), 11, "2025-10-06", "10:00", 90),
+            entry(1, EntryType::Exercise(ExerciseType::Tutorial(vec![2])), 11, "2025-10-07", "10:00", 90),
+            entry(2, EntryType::Lecture, 10, "2026-03-02", "12:00", 60),
+        ],
+    )
+}
+
+async fn get(app: &Router, uri: &str) -> (StatusCode, String) {
+    let response = app
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+async fn get_json(app: &Router, uri: &str) -> (StatusCode, Value) {
+    let (status, body) = get(app, uri).await;
+    (status, serde_json::from_str(&body).unwrap())
+}
+
+#[tokio::test]
+async fn catalogs() {
+    let app = router(AppState::new(test_db()));
+
+    let (status, body) = get_json(&app, "/api/main-programs").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body[0]["name"], "MAG-1");
+    assert_eq!(body[0]["level"], "Msc");
+    assert_eq!(body[0]["year"], 1);
+
+    let (_, body) = get_json(&app, "/api/subjects?semester=2").await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["abbr"], "MLTR");
+
+    let (_, body) = get_json(&app, "/api/teachers").await;
+    assert_eq!(body[0]["name"], "Ana Novak");
+
+    let (_, body) = get_json(&app, "/api/work-free-days").await;
+    assert_eq!(body[0], "2025-10-31");
+}
+
+#[tokio::test]
+async fn entries_are_filtered_by_query_options() {
+    let app = router(AppState::new(test_db()));
+
+    let (_, body) = get_json(&app, "/api/entries?semester=1").await;
+    assert_eq!(body.as_array().unwrap().len(), 3);
+
+    let (_, body) = get_json(&app, "/api/entries?date=2025-10-06&classroom=11").await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["start_time"], "10:00:00");
+    assert_eq!(body[0]["duration"], 90);
+
+    let (_, body) = get_json(&app, "/api/entries?main_program=0&week=41&teacher=7&program=85").await;
+    assert_eq!(body.as_array().unwrap().len(), 3);
+
+    // Lecture and tutorial group 1 only
+    let (_, body) = get_json(&app, "/api/entries?selection=1.t1").await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn entry_by_id() {
+    let db = test_db();
+    let id = db.entries()[0].id;
+    let app = router(AppState::new(db));
+
+    let (status, body) = get_json(&app, &format!("/api/entries/{id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["id"], id.to_string());
+
+    let (status, body) = get_json(&app, "/api/entries/0000000000000000").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["status"], 404);
+}
+
+#[tokio::test]
+async fn errors_are_json() {
+    let app = router(AppState::new(test_db()));
+
+    let (status, body) = get_json(&app, "/api/entries?date=yesterday").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"]["status"], 400);
+    assert!(body["error"]["message"].as_str().unwrap().contains("date"));
+
+    let (status, body) = get_json(&app, "/api/entries?selection=abc").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["error"]["message"].is_string());
+
+    let (status, body) = get_json(&app, "/api/nothing-here").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"]["status"], 404);
+}
+
+#[tokio::test]
+async fn free_rooms() {
+    let app = router(AppState::new(test_db()));
+
+    let (_, body) = get_json(&app, "/api/free-rooms?date=2025-10-06&start=09:00&end=10:30").await;
+    assert_eq!(body.as_array().unwrap().len(), 0);
+
+    let (_, body) = get_json(&app, "/api/free-rooms?date=2025-10-06&start=10:00&end=11:00").await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["full_name"], "Predavalnica I/1");
+
+    let (status, _) = get_json(&app, "/api/free-rooms?date=2025-10-06&start=11:00&end=10:00").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn calendar_feed() {
+    let db = test_db();
+    let id = db.entries()[0].id;
+    let app = router(AppState::new(db));
+
+    let (status, body) = get(&app, "/api/calendar/1.t2.ics").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert_eq!(body.matches("BEGIN:VEVENT").count(), 2);
+    assert!(body.contains(&format!("UID:{id}@timetable")));
+    assert!(body.contains("DTSTART;TZID=Europe/Ljubljana:20251006T080000"));
+}
+
+#[tokio::test]
+async fn replaced_database_is_served() {
+    let state = AppState::new(test_db());
+    let app = router(state.clone());
+
+    let mut db = test_db();
+    let first = db.entries()[..1].to_vec();
+    db.set_entries(first);
+    state.replace(db);
+
+    let (_, body) = get_json(&app, "/api/entries").await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+}
@@ -0,0 +1,288 @@
+//! Compact binary encoding of the whole [`Database`] (postcard).
+//!
+//! Entries don't repeat teacher and classroom ids, they point into
+//! the teacher/classroom tables by index. Entry ids are stored as they are,
+//! saved selections and overrides refer to them. ISO weeks are recomputed on load.
+
+use std::collections::{BTreeSet, HashMap};
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+
+use crate::anyhow;
+use crate::db::Database;
+use crate::definitions::{fnv1a_64, Classroom, ClassroomId, Entry, EntryId, EntryType, MainProgram, MainProgramId, Minutes, Offering, Program, ProgramId, Semester, StudyLevel, Subject, SubjectId, Teacher, TeacherId, Track};
+
+/// Bumped whenever the layout below changes
+pub const BUNDLE_VERSION: u16 = 5;
+
+/// Small JSON file published next to the app so clients can tell the bundle changed
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct DataManifest {
+    pub bundle_version: u16,
+    pub fingerprint: String,
+    pub generated: String, // RFC 3339
+}
+
+impl DataManifest {
+    pub fn new(bytes: &[u8], generated: String) -> DataManifest {
+        DataManifest { bundle_version: BUNDLE_VERSION, fingerprint: fingerprint(bytes), generated }
+    }
+}
+
+/// FNV-1a hash of the bundle as hex (same hash as entry ids), stable across platforms
+pub fn fingerprint(bytes: &[u8]) -> String {
+    format!("{:016x}", fnv1a_64(bytes))
+}
+
+#[derive(Serialize, Deserialize)]
+struct Bundle {
+    version: u16,
+    main_programs: Vec<CompactMainProgram>,
+    programs: Vec<CompactProgram>,
+    semesters: Vec<Semester>,
+    subjects: Vec<CompactSubject>,
+    teachers: Vec<Teacher>,
+    classrooms: Vec<Classroom>,
+    work_free_days: Vec<i32>, // days from CE
+    entries: Vec<CompactEntry>,
+}
+
+// `MainProgram` and `Program` deserialize through their JSON records, which postcard can't follow
+#[derive(Serialize, Deserialize)]
+struct CompactMainProgram {
+    id: MainProgramId,
+    name: String,
+    level: StudyLevel,
+    year: u8,
+    program_ids: BTreeSet<ProgramId>,
+}
+
+impl From<&MainProgram> for CompactMainProgram {
+    fn from(mp: &MainProgram) -> Self {
+        CompactMainProgram {
+            id: mp.id,
+            name: mp.name.clone(),
+            level: mp.level,
+            year: mp.year,
+            program_ids: mp.program_ids.clone(),
+        }
+    }
+}
+
+impl From<CompactMainProgram> for MainProgram {
+    fn from(mp: CompactMainProgram) -> Self {
+        MainProgram { id: mp.id, name: mp.name, level: mp.level, year: mp.year, program_ids: mp.program_ids }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CompactProgram {
+    id: ProgramId,
+    name: String,
+    full_name: String,
+    abbr: String,
+    track: Option<Track>,
+    url: String,
+    main_program_id: MainProgramId,
+}
+
+impl From<&Program> for CompactProgram {
+    fn from(p: &Program) -> Self {
+        CompactProgram {
+            id: p.id,
+            name: p.name.clone(),
+            full_name: p.full_name.clone(),
+            abbr: p.abbr.clone(),
+            track: p.track.clone(),
+            url: p.url.clone(),
+            main_program_id: p.main_program_id,
+        }
+    }
+}
+
+impl From<CompactProgram> for Program {
+    fn from(p: CompactProgram) -> Self {
+        Program {
+            id: p.id,
+            name: p.name,
+            full_name: p.full_name,
+            abbr: p.abbr,
+            track: p.track,
+            url: p.url,
+            main_program_id: p.main_program_id,
+        }
+    }
+}
+
+// `Subject` deserializes through its JSON back-compat record, which postcard can't follow
+#[derive(Serialize, Deserialize)]
+struct CompactSubject {
+    id: SubjectId,
+    offerings: Vec<Offering>,
+    name: String,
+    abbr: String,
+    url: String,
+    tutorial_groups: BTreeSet<u32>,
+    lab_groups: BTreeSet<u32>,
+}
+
+impl From<&Subject> for CompactSubject {
+    fn from(s: &Subject) -> Self {
+        CompactSubject {
+            id: s.id,
+            offerings: s.offerings.clone(),
+            name: s.name.clone(),
+            abbr: s.abbr.clone(),
+            url: s.url.clone(),
+            tutorial_groups: s.tutorial_groups.clone(),
+            lab_groups: s.lab_groups.clone(),
+        }
+    }
+}
+
+impl From<CompactSubject> for Subject {
+    fn from(s: CompactSubject) -> Self {
+        Subject {
+            id: s.id,
+            offerings: s.offerings,
+            name: s.name,
+            abbr: s.abbr,
+            url: s.url,
+            tutorial_groups: s.tutorial_groups,
+            lab_groups: s.lab_groups,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CompactEntry {
+    id: u64,
+    subject_id: SubjectId,
+    entry_type: EntryType,
+    teachers: Vec<u16>, // indices into Bundle::teachers
+    classroom: u16,     // index into Bundle::classrooms
+    date: i32,          // days from CE
+    start: u16,         // minutes from midnight
+    duration: u16,      // minutes
+}
+
+
+pub fn encode(db: &Database) -> Result<Vec<u8>> {
+    // Sorted so the same database always gives the same bytes
+    let mut teachers: Vec<&Teacher> = db.teachers.values().collect();
+    teachers.sort_by_key(|t| t.id.0);
+    let mut classrooms: Vec<&Classroom> = db.classrooms.values().collect();
+    classrooms.sort_by_key(|c| c.id.0);
+
+    // Indices are u16, more teachers or classrooms than that can't be referenced
+    let teacher_index: HashMap<TeacherId, u16> = teachers
+        .iter()
+        .enumerate()
+        .map(|(i, t)| Ok((t.id, u16::try_from(i).map_err(|_| anyhow!("Too many teachers for the bundle ({})", teachers.len()))?)))
+        .collect::<Result<_>>()?;
+    let classroom_index: HashMap<ClassroomId, u16> = classrooms
+        .iter()
+        .enumerate()
+        .map(|(i, c)| Ok((c.id, u16::try_from(i).map_err(|_| anyhow!("Too many classrooms for the bundle ({})", classrooms.len()))?)))
+        .collect::<Result<_>>()?;
+
+    let entries = db.entries()
+        .iter()
+        .map(|e| {
+            let mut teachers = e.teacher_ids
+                .iter()
+                .map(|tid| teacher_index.get(tid).copied().ok_or_else(|| anyhow!("Unknown teacher {tid:?} in entry {}", e.id)))
+                .collect::<Result<Vec<_>>>()?;
+            teachers.sort_unstable();
+            let classroom = classroom_index
+                .get(&e.classroom_id)
+                .copied()
+                .ok_or_else(|| anyhow!("Unknown classroom {:?} in entry {}", e.classroom_id, e.id))?;
+            Ok(CompactEntry {
+                id: e.id.0,
+                subject_id: e.subject_id,
+                entry_type: e.entry_type.clone(),
+                teachers,
+                classroom,
+                date: e.date.num_days_from_ce(),
+                start: u16::try_from(e.start_time.hour() * 60 + e.start_time.minute())?,
+                duration: u16::try_from(e.duration.0)
+                    .map_err(|_| anyhow!("Duration {} of entry {} doesn't fit the bundle", e.duration.0, e.id))?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut main_programs: Vec<&MainProgram> = db.main_programs.values().collect();
+    main_programs.sort_by_key(|mp| mp.id.0);
+    let mut programs: Vec<&Program> = db.programs.values().collect();
+    programs.sort_by_key(|p| p.id);
+    let mut subjects: Vec<&Subject> = db.subjects.values().collect();
+    subjects.sort_by_key(|s| s.id);
+
+    let bundle = Bundle {
+        version: BUNDLE_VERSION,
+        main_programs: main_programs.into_iter().map(CompactMainProgram::from).collect(),
+        programs: programs.into_iter().map(CompactProgram::from).collect(),
+        semesters: db.semesters.clone(),
+        subjects: subjects.into_iter().map(CompactSubject::from).collect(),
+        teachers: teachers.into_iter().cloned().collect(),
+        classrooms: classrooms.into_iter().cloned().collect(),
+        work_free_days: db.work_free_days.iter().map(|d| d.num_days_from_ce()).collect(),
+        entries,
+    };
+
+    Ok(postcard::to_stdvec(&bundle)?)
+}
+
+pub fn decode(bytes: &[u8]) -> Result<Database> {
+    // The version leads the bundle, check it before the rest stops parsing
+    let (version, _) = postcard::take_from_bytes::<u16>(bytes)?;
+    if version != BUNDLE_VERSION {
+        return Err(anyhow!("Unsupported bundle version {version} (expected {BUNDLE_VERSION})"));
+    }
+    let bundle: Bundle = postcard::from_bytes(bytes)?;
+
+    let date = |days: i32| {
+        NaiveDate::from_num_days_from_ce_opt(days).ok_or_else(|| anyhow!("Invalid date {days} in bundle"))
+    };
+
+    let entries = bundle.entries
+        .into_iter()
+        .map(|e| {
+            let date = date(e.date)?;
+            let start_time = NaiveTime::from_hms_opt(e.start as u32 / 60, e.start as u32 % 60, 0)
+                .ok_or_else(|| anyhow!("Invalid start time {} in bundle", e.start))?;
+            let teacher_ids = e.teachers
+                .iter()
+                .map(|i| bundle.teachers.get(*i as usize).map(|t| t.id).ok_or_else(|| anyhow!("Teacher index {i} out of range")))
+                .collect::<Result<_>>()?;
+            let classroom_id = bundle.classrooms
+                .get(e.classroom as usize)
+                .map(|c| c.id)
+                .ok_or_else(|| anyhow!("Classroom index {} out of range", e.classroom))?;
+            Ok(Entry {
+                id: EntryId(e.id),
+                subject_id: e.subject_id,
+                entry_type: e.entry_type,
+                teacher_ids,
+                classroom_id,
+                date,
+                week_iso: date.iso_week().week() as i32,
+                start_time,
+                duration: Minutes(e.duration as u32),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Database::new(
+        bundle.main_programs.into_iter().map(|mp| (mp.id, MainProgram::from(mp))).collect(),
+        bundle.programs.into_iter().map(|p| (p.id, Program::from(p))).collect(),
+        bundle.semesters,
+        bundle.subjects.into_iter().map(|s| (s.id, Subject::from(s))).collect(),
+        bundle.teachers.into_iter().map(|t| (t.id, t)).collect(),
+        bundle.classrooms.into_iter().map(|c| (c.id, c)).collect(),
+        bundle.work_free_days.into_iter().map(date).collect::<Result<_>>()?,
+        entries,
+    ))
+}
@@ -0,0 +1,210 @@
+//! CSV export of entries, e.g. for Excel.
+
+use chrono::{Datelike, Weekday};
+
+use crate::anyhow;
+use crate::db::Database;
+use crate::definitions::{Entry, EntryType, ExerciseType};
+use crate::time::semester_of;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Id,
+    Subject,
+    SubjectAbbr,
+    Programs,
+    EntryType,
+    Groups,
+    Teachers,
+    Classroom,
+    Date,
+    Weekday,
+    Start,
+    End,
+    Duration,
+}
+
+impl Column {
+    pub const ALL: [Column; 13] = [
+        Column::Id,
+        Column::Subject,
+        Column::SubjectAbbr,
+        Column::Programs,
+        Column::EntryType,
+        Column::Groups,
+        Column::Teachers,
+        Column::Classroom,
+        Column::Date,
+        Column::Weekday,
+        Column::Start,
+        Column::End,
+        Column::Duration,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Column::Id => "id",
+            Column::Subject => "subject",
+            Column::SubjectAbbr => "subject_abbr",
+            Column::Programs => "programs",
+            Column::EntryType => "entry_type",
+            Column::Groups => "groups",
+            Column::Teachers => "teachers",
+            Column::Classroom => "classroom",
+            Column::Date => "date",
+            Column::Weekday => "weekday",
+            Column::Start => "start",
+            Column::End => "end",
+            Column::Duration => "duration_minutes",
+        }
+    }
+
+    /// Parses a comma separated list of column names like "date,start,subject_abbr"
+    pub fn parse_list(s: &str) -> anyhow::Result<Vec<Column>> {
+        s.split(',')
+            .map(|name| name.trim())
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                Column::ALL
+                    .iter()
+                    .find(|c| c.name() == name)
+                    .copied()
+                    .ok_or_else(|| anyhow!("Unknown CSV column '{name}'"))
+            })
+            .collect()
+    }
+
+    fn value(&self, db: &Database, entry: &Entry) -> String {
+        let subject = db.subjects.get(&entry.subject_id);
+        match self {
+            Column::Id => entry.id.to_string(),
+            Column::Subject => subject.map(|s| s.name.clone()).unwrap_or_default(),
+            Column::SubjectAbbr => subject.map(|s| s.abbr.clone()).unwrap_or_default(),
+            Column::Programs => {
+                // only the offering of the entry's semester, a subject can be in other programs in the other one
+                let semester = semester_of(entry.date);
+                let mut names: Vec<&str> = subject
+                    .into_iter()
+                    .flat_map(|s| s.offerings.iter())
+                    .filter(|o| semester.is_none_or(|sem| o.semester == sem))
+                    .flat_map(|o| o.program_ids.iter())
+                    .filter_map(|id| db.programs.get(id))
+                    .map(|p| p.name.as_str())
+                    .collect();
+                names.sort();
+                names.dedup();
+                names.join(", ")
+            }
+            Column::EntryType => match &entry.entry_type {
+                EntryType::Lecture => "P",
+                EntryType::Exercise(ExerciseType::Tutorial(_)) => "VP",
+                EntryType::Exercise(ExerciseType::Lab(_)) => "VL",
+            }.to_string(),
+            Column::Groups => match &entry.entry_type {
+                EntryType::Lecture => String::new(),
+                EntryType::Exercise(ExerciseType::Tutorial(groups) | ExerciseType::Lab(groups)) => {
+                    groups.iter().map(|g| format!("S{g}")).collect::<Vec<_>>().join(", ")
+                }
+            },
+            Column::Teachers => {
+                let mut names: Vec<&str> = entry.teacher_ids
+                    .iter()
+                    .filter_map(|id| db.teachers.get(id))
+                    .map(|t| t.name.as_str())
+                    .collect();
+                names.sort();
+                names.join(", ")
+            }
+            Column::Classroom => db.classrooms.get(&entry.classroom_id).map(|c| c.full_name.clone()).unwrap_or_default(),
+            Column::Date => entry.date.format("%Y-%m-%d").to_string(),
+            Column::Weekday => weekday_name(entry.date.weekday()).to_string(),
+            Column::Start => entry.start_time.format("%H:%M").to_string(),
+            Column::End => entry.end_time().format("%H:%M").to_string(),
+            Column::Duration => entry.duration.0.to_string(),
+        }
+    }
+}
+
+fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "Ponedeljek",
+        Weekday::Tue => "Torek",
+        Weekday::Wed => "Sreda",
+        Weekday::Thu => "Četrtek",
+        Weekday::Fri => "Petek",
+        Weekday::Sat => "Sobota",
+        Weekday::Sun => "Nedelja",
+    }
+}
+
+pub struct CsvWriter {
+    columns: Vec<Column>,
+    delimiter: char,
+    bom: bool,
+}
+
+impl Default for CsvWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvWriter {
+    /// All columns, `;` delimiter and a UTF-8 BOM, which is what Excel with Slovenian settings expects
+    pub fn new() -> Self {
+        CsvWriter {
+            columns: Column::ALL.to_vec(),
+            delimiter: ';',
+            bom: true,
+        }
+    }
+
+    pub fn columns(mut self, columns: &[Column]) -> Self {
+        self.columns = columns.to_vec();
+        self
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn bom(mut self, bom: bool) -> Self {
+        self.bom = bom;
+        self
+    }
+
+    /// Header row and one row per entry, lines end with CRLF
+    pub fn write(&self, db: &Database, entries: &[&Entry]) -> String {
+        let mut out = String::new();
+        if self.bom {
+            out.push('\u{feff}');
+        }
+
+        let header: Vec<String> = self.columns.iter().map(|c| c.name().to_string()).collect();
+        self.push_row(&mut out, &header);
+        for entry in entries {
+            let row: Vec<String> = self.columns.iter().map(|c| c.value(db, entry)).collect();
+            self.push_row(&mut out, &row);
+        }
+        out
+    }
+
+    fn push_row(&self, out: &mut String, cells: &[String]) {
+        let row = cells
+            .iter()
+            .map(|cell| self.escape(cell))
+            .collect::<Vec<_>>()
+            .join(&self.delimiter.to_string());
+        out.push_str(&row);
+        out.push_str("\r\n");
+    }
+
+    fn escape(&self, cell: &str) -> String {
+        if cell.contains(self.delimiter) || cell.contains('"') || cell.contains('\n') || cell.contains('\r') {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+        } else {
+            cell.to_string()
+        }
+    }
+}
@@ -1,6 +1,8 @@
-use std::{collections::HashMap, fs::File, io};
+use std::{collections::HashMap, fs::File, io::{self, BufReader}, path::Path};
+use chrono::NaiveDate;
 use serde::de::DeserializeOwned;
 
+use crate::db::Database;
 use crate::definitions::{Classroom, ClassroomId, Entry, MainProgram, MainProgramId, Program, ProgramId, Semester, Subject, SubjectId, Teacher, TeacherId};
 
 fn load_from_json<T>(path: &str) -> io::Result<T>
@@ -8,7 +10,7 @@ where
     T: DeserializeOwned,
 {
     let file = File::open(path)?;
-    let data = serde_json::from_reader(file)
+    let data = serde_json::from_reader(BufReader::new(file))
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
     Ok(data)
 }
@@ -34,4 +36,45 @@ pub fn load_subjects_from_json() -> io::Result<HashMap<SubjectId, Subject>> {
 }
 pub fn load_teachers_from_json() -> io::Result<HashMap<TeacherId, Teacher>> {
     load_from_json("static/teachers.json")
-}
\ No newline at end of file
+}
+pub fn load_work_free_days_from_json() -> io::Result<Vec<NaiveDate>> {
+    load_from_json("static/work_free_days.json")
+}
+
+pub fn load_database_from_json() -> io::Result<Database> {
+    load_database_from_json_dir(Path::new("static"))
+}
+
+pub fn load_database_from_bundle() -> io::Result<Database> {
+    load_database_from_bundle_file(Path::new("static/timetable.bin"))
+}
+
+/// Loads static/timetable.bin from `dir` if it exists, otherwise the *.json files
+pub fn load_database(dir: &Path) -> io::Result<Database> {
+    let bundle = dir.join("timetable.bin");
+    if bundle.exists() {
+        load_database_from_bundle_file(&bundle)
+    } else {
+        load_database_from_json_dir(dir)
+    }
+}
+
+pub fn load_database_from_json_dir(dir: &Path) -> io::Result<Database> {
+    let path = |name: &str| dir.join(name).to_string_lossy().into_owned();
+    Ok(Database::new(
+        load_from_json(&path("main_programs.json"))?,
+        load_from_json(&path("programs.json"))?,
+        load_from_json(&path("semesters.json"))?,
+        load_from_json(&path("subjects.json"))?,
+        load_from_json(&path("teachers.json"))?,
+        load_from_json(&path("classrooms.json"))?,
+        load_from_json(&path("work_free_days.json"))?,
+        load_from_json(&path("entries.json"))?,
+    ))
+}
+
+pub fn load_database_from_bundle_file(path: &Path) -> io::Result<Database> {
+    let bytes = std::fs::read(path)?;
+    Database::from_bundle(&bytes)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
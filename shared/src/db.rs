@@ -1,11 +1,14 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use anyhow::Result;
 use chrono::{Datelike, NaiveDate};
 use serde::{Serialize};
 
-use crate::definitions::{Classroom, ClassroomId, Entry, MainProgram, MainProgramId, Program, ProgramId, Semester, Subject, SubjectId, Teacher, TeacherId};
+use crate::bundle;
+use crate::time::semester_of;
+use crate::definitions::{Classroom, ClassroomId, Entry, EntryId, MainProgram, MainProgramId, Program, ProgramId, Semester, Subject, SubjectId, Teacher, TeacherId};
 
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, PartialEq)]
 pub struct Database {
     pub main_programs: HashMap<MainProgramId, MainProgram>,
     pub programs: HashMap<ProgramId, Program>,
@@ -14,15 +17,75 @@ pub struct Database {
     pub teachers: HashMap<TeacherId, Teacher>,
     pub classrooms: HashMap<ClassroomId, Classroom>,
     pub work_free_days: Vec<NaiveDate>,
-    pub entries: Vec<Entry>,
+    // private so `entry_index` can't go stale, see `entries` and `set_entries`
+    entries: Vec<Entry>,
+    /// Position of each entry in `entries`
+    #[serde(skip)]
+    entry_index: HashMap<EntryId, usize>,
 }
 impl Database {
-    pub fn query(&self) -> Query {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        main_programs: HashMap<MainProgramId, MainProgram>,
+        programs: HashMap<ProgramId, Program>,
+        semesters: Vec<Semester>,
+        subjects: HashMap<SubjectId, Subject>,
+        teachers: HashMap<TeacherId, Teacher>,
+        classrooms: HashMap<ClassroomId, Classroom>,
+        work_free_days: Vec<NaiveDate>,
+        entries: Vec<Entry>,
+    ) -> Database {
+        let mut db = Database {
+            main_programs,
+            programs,
+            semesters,
+            subjects,
+            teachers,
+            classrooms,
+            work_free_days,
+            entries: vec![],
+            entry_index: HashMap::new(),
+        };
+        db.set_entries(entries);
+        db
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Replaces all entries and reindexes them
+    pub fn set_entries(&mut self, entries: Vec<Entry>) {
+        self.entry_index = entries.iter().enumerate().map(|(i, entry)| (entry.id, i)).collect();
+        self.entries = entries;
+    }
+
+    /// Load from the compact binary bundle (see [`bundle`])
+    pub fn from_bundle(bytes: &[u8]) -> Result<Database> {
+        bundle::decode(bytes)
+    }
+
+    pub fn to_bundle(&self) -> Result<Vec<u8>> {
+        bundle::encode(self)
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub fn from_sqlite(path: impl AsRef<std::path::Path>) -> Result<Database> {
+        crate::sqlite::read(path)
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub fn to_sqlite(&self, path: impl AsRef<std::path::Path>) -> Result<()> {
+        crate::sqlite::write(self, path)
+    }
+
+    pub fn query(&self) -> Query<'_> {
         Query {
             db: self,
             main_program: None,
             program: None,
             subject: None,
+            subjects: None,
             teacher: None,
             classroom: None,
             semester: None,
@@ -38,11 +101,15 @@ impl Database {
     ) -> Vec<Subject> {
         self.subjects
             .values()
-            .filter(|subject| subject.main_program_id == *main_program_id && subject.semester == semester)
+            .filter(|subject| subject.offered_in(main_program_id, semester))
             .cloned()
             .collect()
     }
 
+    pub fn entry(&self, id: &EntryId) -> Option<&Entry> {
+        self.entry_index.get(id).map(|i| &self.entries[*i])
+    }
+
 }
 
 
@@ -51,6 +118,7 @@ pub struct Query<'a> {
     main_program: Option<&'a MainProgramId>,
     program: Option<&'a ProgramId>,
     subject: Option<&'a SubjectId>,
+    subjects: Option<&'a BTreeSet<SubjectId>>,
     teacher: Option<&'a TeacherId>,
     classroom: Option<&'a ClassroomId>,
     semester: Option<Semester>,
@@ -75,6 +143,12 @@ impl<'a> Query<'a> {
         self
     }
 
+    /// Any of these subjects, regardless of program (e.g. a student's own picks)
+    pub fn subjects(mut self, ids: &'a BTreeSet<SubjectId>) -> Self {
+        self.subjects = Some(ids);
+        self
+    }
+
     pub fn teacher(mut self, id: &'a TeacherId) -> Self {
         self.teacher = Some(id);
         self
@@ -114,6 +188,12 @@ impl<'a> Query<'a> {
                 }
             }
 
+            if let Some(subject_ids) = self.subjects {
+                if !subject_ids.contains(&entry.subject_id) {
+                    return false;
+                }
+            }
+
             // Filter by teacher
             if let Some(teacher_id) = self.teacher {
                 if !entry.teacher_ids.contains(teacher_id) {
@@ -151,23 +231,19 @@ impl<'a> Query<'a> {
 
             // Subject-based filters require looking up in `subjects`
             if let Some(subject) = self.db.subjects.get(&entry.subject_id) {
-                // Semester filter
-                if let Some(sem) = self.semester {
-                    if subject.semester != sem {
-                        return false;
-                    }
-                }
-
-                // Main program filter
-                if let Some(mp_id) = self.main_program {
-                    if &subject.main_program_id != mp_id {
-                        return false;
-                    }
+                // Semester, main program and program have to match the same offering
+                let offered = subject.offerings.iter().any(|o| {
+                    self.semester.is_none_or(|sem| o.semester == sem)
+                        && self.main_program.is_none_or(|mp_id| &o.main_program_id == mp_id)
+                        && self.program.is_none_or(|p_id| o.program_ids.contains(p_id))
+                });
+                if !offered {
+                    return false;
                 }
 
-                // Program filter
-                if let Some(p_id) = self.program {
-                    if !&subject.program_ids.contains(p_id) {
+                // A subject taught in both semesters has entries in both, keep the asked one
+                if let Some(sem) = self.semester {
+                    if semester_of(entry.date).is_some_and(|s| s != sem) {
                         return false;
                     }
                 }
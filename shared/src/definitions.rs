@@ -1,11 +1,14 @@
 use std::collections::{BTreeSet, HashSet};
 use anyhow::Result;
 use crate::anyhow;
-use chrono::{NaiveDate, NaiveTime};
-use serde::{Serialize, Deserialize};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use serde::{Deserializer, Serialize, Deserialize};
+
+use crate::programs::{parse_main_program, ProgramLabel, ProgramParseError};
+use crate::time::ranges_overlap;
 
 // Unique identifiers
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Copy, Clone, Ord, PartialOrd)]
 pub struct MainProgramId(pub u32);
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Copy, Clone, Ord, PartialOrd)]
@@ -20,51 +23,296 @@ pub struct ClassroomId(pub u32);
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Copy, Clone, Ord, PartialOrd)]
 pub struct SubjectId(pub u32);
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+/// Stable hash of subject, type + groups, date, start time and classroom.
+/// Serialized as 16 hex digits so it survives JS number precision.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Ord, PartialOrd)]
+pub struct EntryId(pub u64);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Semester(pub u8);
 
+/// Length of an entry in whole minutes (90 = 1h 30min)
+#[derive(Debug, Serialize, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Default)]
+pub struct Minutes(pub u32);
+
+impl Minutes {
+    pub fn from_hm(hours: u32, minutes: u32) -> Self {
+        Minutes(hours * 60 + minutes)
+    }
+
+    pub fn hours(&self) -> u32 {
+        self.0 / 60
+    }
+
+    pub fn minutes(&self) -> u32 {
+        self.0 % 60
+    }
+
+    pub fn to_duration(&self) -> chrono::Duration {
+        chrono::Duration::minutes(self.0 as i64)
+    }
+}
+
+impl std::fmt::Display for Minutes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{:02}", self.hours(), self.minutes())
+    }
+}
+
+// Accepts plain minutes (90) and the old "01:30:00" time-of-day strings
+impl<'de> Deserialize<'de> for Minutes {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Minutes(u32),
+            Time(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Minutes(m) => Ok(Minutes(m)),
+            Raw::Time(s) => NaiveTime::parse_from_str(&s, "%H:%M:%S")
+                .or_else(|_| NaiveTime::parse_from_str(&s, "%H:%M"))
+                .map(|t| Minutes::from_hm(t.hour(), t.minute()))
+                .map_err(|e| serde::de::Error::custom(format!("Invalid duration '{s}': {e}"))),
+        }
+    }
+}
+
+
+impl EntryId {
+    pub fn new(
+        subject_id: SubjectId,
+        entry_type: &EntryType,
+        date: NaiveDate,
+        start_time: NaiveTime,
+        classroom_id: ClassroomId,
+    ) -> Self {
+        // Groups are sorted so "VP(S2, S1)" and "VP(S1, S2)" give the same id
+        let type_key = match entry_type {
+            EntryType::Lecture => "P".to_string(),
+            EntryType::Exercise(ex) => {
+                let (prefix, groups) = match ex {
+                    ExerciseType::Tutorial(groups) => ("VP", groups),
+                    ExerciseType::Lab(groups) => ("VL", groups),
+                };
+                let mut groups = groups.clone();
+                groups.sort_unstable();
+                groups.dedup();
+                let groups = groups.iter().map(|g| g.to_string()).collect::<Vec<_>>().join(",");
+                format!("{prefix}({groups})")
+            }
+        };
+        let key = format!(
+            "{}|{type_key}|{}|{}|{}",
+            subject_id.0,
+            date.format("%Y-%m-%d"),
+            start_time.format("%H:%M"),
+            classroom_id.0,
+        );
+        EntryId(fnv1a_64(key.as_bytes()))
+    }
+}
+
+impl std::fmt::Display for EntryId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl std::str::FromStr for EntryId {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        u64::from_str_radix(s, 16).map(EntryId)
+    }
+}
+
+impl Serialize for EntryId {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EntryId {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(|e| serde::de::Error::custom(format!("Invalid entry id '{s}': {e}")))
+    }
+}
+
+// FNV-1a, fixed so ids don't change between Rust versions like std's hasher may
+pub(crate) fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for b in bytes {
+        hash ^= *b as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
 
 // Structs with data
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum StudyLevel {
+    BscProfessional, // PAP
+    BscAcademic,     // RRP
+    Msc,             // MAG
+}
+
+impl StudyLevel {
+    pub const ALL: [StudyLevel; 3] = [StudyLevel::BscProfessional, StudyLevel::BscAcademic, StudyLevel::Msc];
+
+    /// Prefix of the main program names, "MAG" in "MAG-2"
+    pub fn code(&self) -> &'static str {
+        match self {
+            StudyLevel::BscProfessional => "PAP",
+            StudyLevel::BscAcademic => "RRP",
+            StudyLevel::Msc => "MAG",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<StudyLevel> {
+        StudyLevel::ALL.into_iter().find(|level| level.code() == code)
+    }
+}
+
+/// Direction within a study year, e.g. PCS, "Procesno strojništvo"
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct Track {
+    pub abbr: String,
+    pub name: String,
+}
+
 /// PAP-1, RRP-3, MAG-2, ...
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
+/// Level and year are parsed from the name when the record doesn't have them.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone)]
+#[serde(try_from = "MainProgramRecord")]
 pub struct MainProgram {
     pub id: MainProgramId,
     pub name: String,
+    pub level: StudyLevel,
+    pub year: u8,
     pub program_ids: BTreeSet<ProgramId>,
 }
 
+#[derive(Deserialize)]
+struct MainProgramRecord {
+    id: MainProgramId,
+    name: String,
+    level: Option<StudyLevel>,
+    year: Option<u8>,
+    program_ids: BTreeSet<ProgramId>,
+}
+
+impl TryFrom<MainProgramRecord> for MainProgram {
+    type Error = ProgramParseError;
+
+    fn try_from(r: MainProgramRecord) -> Result<Self, Self::Error> {
+        match (r.level, r.year) {
+            (Some(level), Some(year)) => Ok(MainProgram { id: r.id, name: r.name, level, year, program_ids: r.program_ids }),
+            _ => MainProgram::new(r.id, r.name, r.program_ids),
+        }
+    }
+}
+
+impl MainProgram {
+    pub fn new(id: MainProgramId, name: String, program_ids: BTreeSet<ProgramId>) -> Result<MainProgram, ProgramParseError> {
+        let (level, year) = parse_main_program(&name)?;
+        Ok(MainProgram { id, name, level, year, program_ids })
+    }
+}
+
 /// (MAG-2) (PCS-MAG), (PAP-2) (PPL-PAP), ...
+/// Name, abbreviation and track are parsed from the full name when the record doesn't have them.
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+#[serde(try_from = "ProgramRecord")]
 pub struct Program {
     pub id: ProgramId,
     pub name: String,
     pub full_name: String,
     pub abbr: String,
+    pub track: Option<Track>,
     pub url: String,
     pub main_program_id: MainProgramId,
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
+#[derive(Deserialize)]
+struct ProgramRecord {
+    id: ProgramId,
+    name: Option<String>,
+    full_name: String,
+    abbr: Option<String>,
+    #[serde(default)]
+    track: Option<Track>, // null for programs that are a whole main program
+    url: String,
+    main_program_id: MainProgramId,
+}
+
+impl TryFrom<ProgramRecord> for Program {
+    type Error = ProgramParseError;
+
+    fn try_from(r: ProgramRecord) -> Result<Self, Self::Error> {
+        match (r.name, r.abbr) {
+            (Some(name), Some(abbr)) => Ok(Program {
+                id: r.id,
+                name,
+                full_name: r.full_name,
+                abbr,
+                track: r.track,
+                url: r.url,
+                main_program_id: r.main_program_id,
+            }),
+            _ => Program::new(r.id, r.full_name, r.url, r.main_program_id),
+        }
+    }
+}
+
+impl Program {
+    pub fn new(id: ProgramId, full_name: String, url: String, main_program_id: MainProgramId) -> Result<Program, ProgramParseError> {
+        let label = ProgramLabel::parse(&full_name)?;
+        let track = label.track()?;
+        Ok(Program { id, name: label.name, full_name, abbr: label.abbr, track, url, main_program_id })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone)]
 pub struct Teacher {
     pub id: TeacherId,
     pub name: String,
     pub url: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Hash, Eq, PartialEq, Clone)]
 pub struct Classroom {
     pub id: ClassroomId,
     pub full_name: String,
     pub url: String,
 }
 
+/// Where a subject is taught: a main program, the programs of it that list the subject, and the semester
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone)]
-pub struct Subject {
-    pub id: SubjectId,
-    pub program_ids: BTreeSet<ProgramId>,
+pub struct Offering {
     pub main_program_id: MainProgramId,
+    pub program_ids: BTreeSet<ProgramId>,
     pub semester: Semester,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Clone)]
+#[serde(from = "SubjectRecord")]
+pub struct Subject {
+    pub id: SubjectId,
+    pub offerings: Vec<Offering>, // one per main program and semester, sorted
     pub name: String,
     pub abbr: String,
     pub url: String,
@@ -72,8 +320,80 @@ pub struct Subject {
     pub lab_groups: BTreeSet<u32>,
 }
 
+// subjects.json written before offerings has a single main program and semester per subject
+#[derive(Deserialize)]
+struct SubjectRecord {
+    id: SubjectId,
+    #[serde(default)]
+    offerings: Vec<Offering>,
+    program_ids: Option<BTreeSet<ProgramId>>,
+    main_program_id: Option<MainProgramId>,
+    semester: Option<Semester>,
+    name: String,
+    abbr: String,
+    url: String,
+    tutorial_groups: BTreeSet<u32>,
+    lab_groups: BTreeSet<u32>,
+}
+
+impl From<SubjectRecord> for Subject {
+    fn from(r: SubjectRecord) -> Self {
+        let mut subject = Subject {
+            id: r.id,
+            offerings: vec![],
+            name: r.name,
+            abbr: r.abbr,
+            url: r.url,
+            tutorial_groups: r.tutorial_groups,
+            lab_groups: r.lab_groups,
+        };
+        for offering in r.offerings {
+            subject.add_offering(offering);
+        }
+        if let (Some(main_program_id), Some(semester)) = (r.main_program_id, r.semester) {
+            subject.add_offering(Offering { main_program_id, program_ids: r.program_ids.unwrap_or_default(), semester });
+        }
+        subject
+    }
+}
+
+impl Subject {
+    /// Merges into the offering of the same main program and semester, if there is one
+    pub fn add_offering(&mut self, offering: Offering) {
+        match self.offerings
+            .iter_mut()
+            .find(|o| o.main_program_id == offering.main_program_id && o.semester == offering.semester)
+        {
+            Some(existing) => existing.program_ids.extend(offering.program_ids),
+            None => {
+                self.offerings.push(offering);
+                self.offerings.sort_by_key(|o| (o.main_program_id, o.semester));
+            }
+        }
+    }
+
+    pub fn offered_in(&self, main_program_id: &MainProgramId, semester: Semester) -> bool {
+        self.offerings.iter().any(|o| &o.main_program_id == main_program_id && o.semester == semester)
+    }
+
+    pub fn main_program_ids(&self) -> BTreeSet<MainProgramId> {
+        self.offerings.iter().map(|o| o.main_program_id).collect()
+    }
+
+    /// Programs of all offerings
+    pub fn program_ids(&self) -> BTreeSet<ProgramId> {
+        self.offerings.iter().flat_map(|o| o.program_ids.iter().copied()).collect()
+    }
+
+    pub fn semesters(&self) -> BTreeSet<Semester> {
+        self.offerings.iter().map(|o| o.semester).collect()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(from = "EntryRecord")]
 pub struct Entry {
+    pub id: EntryId,
     pub subject_id: SubjectId,
     pub entry_type: EntryType,
     pub teacher_ids: HashSet<TeacherId>,
@@ -81,7 +401,62 @@ pub struct Entry {
     pub date: NaiveDate,
     pub week_iso: i32,
     pub start_time: NaiveTime,
-    pub duration: NaiveTime,
+    pub duration: Minutes,
+}
+
+// Bundles written before entries had ids don't contain the `id` field
+#[derive(Deserialize)]
+struct EntryRecord {
+    id: Option<EntryId>,
+    subject_id: SubjectId,
+    entry_type: EntryType,
+    teacher_ids: HashSet<TeacherId>,
+    classroom_id: ClassroomId,
+    date: NaiveDate,
+    week_iso: i32,
+    start_time: NaiveTime,
+    duration: Minutes,
+}
+
+impl From<EntryRecord> for Entry {
+    fn from(r: EntryRecord) -> Self {
+        let id = r.id.unwrap_or_else(|| {
+            EntryId::new(r.subject_id, &r.entry_type, r.date, r.start_time, r.classroom_id)
+        });
+        Entry {
+            id,
+            subject_id: r.subject_id,
+            entry_type: r.entry_type,
+            teacher_ids: r.teacher_ids,
+            classroom_id: r.classroom_id,
+            date: r.date,
+            week_iso: r.week_iso,
+            start_time: r.start_time,
+            duration: r.duration,
+        }
+    }
+}
+
+impl Entry {
+    pub fn end_time(&self) -> NaiveTime {
+        self.start_time + self.duration.to_duration()
+    }
+
+    pub fn start_datetime(&self) -> NaiveDateTime {
+        self.date.and_time(self.start_time)
+    }
+
+    pub fn end_datetime(&self) -> NaiveDateTime {
+        self.start_datetime() + self.duration.to_duration()
+    }
+
+    /// True if both entries take place at the same time (touching ends don't count)
+    pub fn overlaps(&self, other: &Entry) -> bool {
+        ranges_overlap(
+            (self.start_datetime(), self.end_datetime()),
+            (other.start_datetime(), other.end_datetime()),
+        )
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -0,0 +1,135 @@
+//! iCalendar (RFC 5545) output. Times are local to Europe/Ljubljana.
+
+use chrono::{NaiveDateTime, Utc};
+
+use crate::db::Database;
+use crate::definitions::Entry;
+
+pub const BASE_URL: &str = "https://urnik.fs.uni-lj.si";
+
+const TIMEZONE: &str = "Europe/Ljubljana";
+const VTIMEZONE: &str = "BEGIN:VTIMEZONE
+TZID:Europe/Ljubljana
+BEGIN:DAYLIGHT
+TZOFFSETFROM:+0100
+TZOFFSETTO:+0200
+TZNAME:CEST
+DTSTART:19700329T020000
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU
+END:DAYLIGHT
+BEGIN:STANDARD
+TZOFFSETFROM:+0200
+TZOFFSETTO:+0100
+TZNAME:CET
+DTSTART:19701025T030000
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU
+END:STANDARD
+END:VTIMEZONE";
+
+/// One VEVENT
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub uid: String,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub summary: String,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+}
+
+impl Event {
+    /// Uid is the entry id, so re-generated calendars update events instead of duplicating them
+    pub fn from_entry(db: &Database, entry: &Entry) -> Event {
+        let subject = db.subjects.get(&entry.subject_id);
+        let mut teachers: Vec<&str> = entry.teacher_ids
+            .iter()
+            .filter_map(|id| db.teachers.get(id))
+            .map(|t| t.name.as_str())
+            .collect();
+        teachers.sort();
+
+        Event {
+            uid: format!("{}@timetable", entry.id),
+            start: entry.start_datetime(),
+            end: entry.end_datetime(),
+            summary: match subject {
+                Some(s) => format!("{} ({})", s.name, entry.entry_type),
+                None => entry.entry_type.to_string(),
+            },
+            location: db.classrooms.get(&entry.classroom_id).map(|c| c.full_name.clone()),
+            description: (!teachers.is_empty()).then(|| teachers.join(", ")),
+            url: subject.map(|s| format!("{BASE_URL}{}", s.url)),
+        }
+    }
+}
+
+/// Whole VCALENDAR with CRLF line endings and folded long lines
+pub fn calendar(name: &str, events: &[Event]) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+    let mut lines: Vec<String> = vec![
+        "BEGIN:VCALENDAR".into(),
+        "VERSION:2.0".into(),
+        "PRODID:-//timetable-rs//EN".into(),
+        "CALSCALE:GREGORIAN".into(),
+        "METHOD:PUBLISH".into(),
+        format!("X-WR-CALNAME:{}", escape(name)),
+        format!("X-WR-TIMEZONE:{TIMEZONE}"),
+    ];
+    lines.extend(VTIMEZONE.lines().map(String::from));
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".into());
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{stamp}"));
+        lines.push(format!("DTSTART;TZID={TIMEZONE}:{}", event.start.format("%Y%m%dT%H%M%S")));
+        lines.push(format!("DTEND;TZID={TIMEZONE}:{}", event.end.format("%Y%m%dT%H%M%S")));
+        lines.push(format!("SUMMARY:{}", escape(&event.summary)));
+        if let Some(location) = &event.location {
+            lines.push(format!("LOCATION:{}", escape(location)));
+        }
+        if let Some(description) = &event.description {
+            lines.push(format!("DESCRIPTION:{}", escape(description)));
+        }
+        if let Some(url) = &event.url {
+            lines.push(format!("URL:{url}"));
+        }
+        lines.push("END:VEVENT".into());
+    }
+    lines.push("END:VCALENDAR".into());
+
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&fold(&line));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+pub fn calendar_for_entries(db: &Database, name: &str, entries: &[&Entry]) -> String {
+    let events: Vec<Event> = entries.iter().map(|e| Event::from_entry(db, e)).collect();
+    calendar(name, &events)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Lines longer than 75 bytes continue on the next line after a space
+fn fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            len = 1;
+        }
+        out.push(c);
+        len += c.len_utf8();
+    }
+    out
+}
@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+use chrono::{Datelike, NaiveTime, Weekday};
+
+use crate::definitions::Entry;
+use crate::time::ranges_overlap;
+
+/// Something drawn as a box in the week grid
+pub trait Slot {
+    fn weekday(&self) -> Weekday;
+    fn start_time(&self) -> NaiveTime;
+    fn end_time(&self) -> NaiveTime;
+}
+
+impl Slot for Entry {
+    fn weekday(&self) -> Weekday {
+        self.date.weekday()
+    }
+
+    fn start_time(&self) -> NaiveTime {
+        self.start_time
+    }
+
+    fn end_time(&self) -> NaiveTime {
+        Entry::end_time(self)
+    }
+}
+
+impl<T: Slot> Slot for &T {
+    fn weekday(&self) -> Weekday {
+        (*self).weekday()
+    }
+
+    fn start_time(&self) -> NaiveTime {
+        (*self).start_time()
+    }
+
+    fn end_time(&self) -> NaiveTime {
+        (*self).end_time()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub column: usize,
+    pub total_columns: usize, // same for every slot of that day
+}
+
+/// Splits overlapping slots of each day into side-by-side columns.
+/// Each slot goes into the first column where it overlaps nothing.
+/// Returns placements in the same order as `slots`.
+pub fn arrange_columns<T: Slot>(slots: &[T]) -> Vec<Placement> {
+    let mut by_day: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
+    for (i, slot) in slots.iter().enumerate() {
+        by_day.entry(slot.weekday().num_days_from_monday()).or_default().push(i);
+    }
+
+    let mut placements = vec![Placement { column: 0, total_columns: 1 }; slots.len()];
+    for day_slots in by_day.values() {
+        let mut columns: Vec<Vec<usize>> = Vec::new();
+
+        for &i in day_slots {
+            let span = (slots[i].start_time(), slots[i].end_time());
+            let free = columns.iter().position(|col| {
+                col.iter().all(|&j| !ranges_overlap(span, (slots[j].start_time(), slots[j].end_time())))
+            });
+            match free {
+                Some(c) => columns[c].push(i),
+                None => columns.push(vec![i]),
+            }
+        }
+
+        for (column, col) in columns.iter().enumerate() {
+            for &i in col {
+                placements[i] = Placement { column, total_columns: columns.len() };
+            }
+        }
+    }
+    placements
+}
@@ -4,3 +4,12 @@ pub mod definitions;
 pub mod time;
 pub mod data;
 pub mod db;
+pub mod bundle;
+pub mod selection;
+pub mod layout;
+pub mod ics;
+pub mod csv;
+pub mod search;
+pub mod programs;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
@@ -0,0 +1,85 @@
+//! Parsing of the study structure out of program labels like
+//! "Procesno strojništvo (MAG-2) (PCS-MAG)" and main program names like "RRP-3".
+
+use std::fmt;
+
+use crate::definitions::{StudyLevel, Track};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgramParseError {
+    /// Label without "(MAG-2)"
+    MissingMainProgram(String),
+    /// Label without the second parentheses, "(PCS-MAG)"
+    MissingAbbr(String),
+    UnknownLevel(String),
+    InvalidYear(String),
+}
+
+impl fmt::Display for ProgramParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgramParseError::MissingMainProgram(label) => write!(f, "No main program in parentheses in '{label}'"),
+            ProgramParseError::MissingAbbr(label) => write!(f, "No program abbreviation in parentheses in '{label}'"),
+            ProgramParseError::UnknownLevel(name) => write!(f, "Unknown study level in main program '{name}'"),
+            ProgramParseError::InvalidYear(name) => write!(f, "No study year in main program '{name}'"),
+        }
+    }
+}
+
+impl std::error::Error for ProgramParseError {}
+
+/// Parts of a program label, e.g. name "Procesno strojništvo", main program "MAG-2", abbr "PCS-MAG"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramLabel {
+    pub name: String,
+    pub main_program: String,
+    pub abbr: String,
+}
+
+impl ProgramLabel {
+    pub fn parse(label: &str) -> Result<ProgramLabel, ProgramParseError> {
+        let mut parens = parenthesized(label);
+        let main_program = parens.next().ok_or_else(|| ProgramParseError::MissingMainProgram(label.to_string()))?;
+        let abbr = parens.next().ok_or_else(|| ProgramParseError::MissingAbbr(label.to_string()))?;
+        let name = label[..label.find('(').unwrap_or(label.len())].trim();
+
+        Ok(ProgramLabel { name: name.to_string(), main_program: main_program.to_string(), abbr: abbr.to_string() })
+    }
+
+    /// None when the program is the whole main program ("RRP-1 (RRP-1) (RRP-1)")
+    pub fn track(&self) -> Result<Option<Track>, ProgramParseError> {
+        if self.abbr == self.main_program {
+            return Ok(None);
+        }
+        let (level, _) = parse_main_program(&self.main_program)?;
+        let abbr = self.abbr
+            .strip_suffix(level.code())
+            .and_then(|abbr| abbr.strip_suffix('-'))
+            .unwrap_or(&self.abbr);
+        Ok(Some(Track { abbr: abbr.to_string(), name: self.name.clone() }))
+    }
+}
+
+/// "MAG-2" -> (MSc, 2)
+pub fn parse_main_program(name: &str) -> Result<(StudyLevel, u8), ProgramParseError> {
+    let (code, year) = name.split_once('-').ok_or_else(|| ProgramParseError::InvalidYear(name.to_string()))?;
+    let level = StudyLevel::from_code(code).ok_or_else(|| ProgramParseError::UnknownLevel(name.to_string()))?;
+    let year = year
+        .parse::<u8>()
+        .ok()
+        .filter(|year| *year > 0)
+        .ok_or_else(|| ProgramParseError::InvalidYear(name.to_string()))?;
+    Ok((level, year))
+}
+
+/// Contents of each "(...)" in order, an unclosed one ends the list
+fn parenthesized(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let start = rest.find('(')? + 1;
+        let end = rest[start..].find(')')? + start;
+        let inner = rest[start..end].trim();
+        rest = &rest[end + 1..];
+        Some(inner)
+    })
+}
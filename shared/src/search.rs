@@ -0,0 +1,128 @@
+//! Diacritic-insensitive fuzzy search over subjects, teachers and classrooms.
+
+use crate::db::Database;
+use crate::definitions::{ClassroomId, SubjectId, TeacherId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchHit {
+    Subject(SubjectId),
+    Teacher(TeacherId),
+    Classroom(ClassroomId),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult {
+    pub hit: SearchHit,
+    pub label: String,
+    pub score: u32,
+}
+
+/// Lowercase with diacritics removed, "Četrtek" -> "cetrtek"
+pub fn normalize(s: &str) -> String {
+    s.chars().flat_map(char::to_lowercase).map(fold).collect()
+}
+
+fn fold(c: char) -> char {
+    match c {
+        'č' | 'ć' | 'ç' => 'c',
+        'š' | 'ś' => 's',
+        'ž' | 'ź' | 'ż' => 'z',
+        'đ' | 'ď' => 'd',
+        'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' | 'ą' => 'a',
+        'é' | 'è' | 'ê' | 'ë' | 'ě' | 'ę' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ő' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' | 'ů' | 'ű' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'ń' | 'ň' | 'ñ' => 'n',
+        'ř' => 'r',
+        'ť' => 't',
+        'ľ' | 'ĺ' | 'ł' => 'l',
+        c => c,
+    }
+}
+
+/// Scores `query` against `target`, both already normalized. Every query word has to match
+/// some target word as a whole word, prefix, substring or, weakest, as a subsequence
+pub fn fuzzy_score(query: &str, target: &str) -> Option<u32> {
+    let words: Vec<&str> = target.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect();
+    let mut total = 0;
+    for token in query.split_whitespace() {
+        let best = words.iter().filter_map(|word| word_score(token, word)).max();
+        let best = best.or_else(|| target.contains(token).then_some(40))?;
+        total += best;
+    }
+    (total > 0).then_some(total)
+}
+
+fn word_score(token: &str, word: &str) -> Option<u32> {
+    if word == token {
+        Some(100)
+    } else if word.starts_with(token) {
+        Some(80)
+    } else if word.contains(token) {
+        Some(60)
+    } else {
+        subsequence_score(token, word)
+    }
+}
+
+// Every char of `token` appears in order in `word`, fewer gaps score higher
+fn subsequence_score(token: &str, word: &str) -> Option<u32> {
+    if token.chars().count() < 2 || !word.starts_with(token.chars().next()?) {
+        return None;
+    }
+    let mut chars = word.chars();
+    let mut gaps = 0;
+    for c in token.chars() {
+        loop {
+            match chars.next() {
+                Some(w) if w == c => break,
+                Some(_) => gaps += 1,
+                None => return None,
+            }
+        }
+    }
+    Some(30u32.saturating_sub(gaps * 3).max(1))
+}
+
+impl Database {
+    /// Best `limit` matches, highest score first
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        let query = normalize(query);
+        if query.trim().is_empty() {
+            return vec![];
+        }
+
+        let subjects = self.subjects.values().filter_map(|s| {
+            let by_name = fuzzy_score(&query, &normalize(&s.name));
+            // exact abbreviation ("PAPM") wins over any name match, "pap" scores like a name prefix
+            let abbr = normalize(&s.abbr);
+            let by_abbr = if abbr == query.trim() { Some(200) } else { fuzzy_score(&query, &abbr) };
+            by_abbr.max(by_name).map(|score| SearchResult {
+                hit: SearchHit::Subject(s.id),
+                label: format!("{} ({})", s.name, s.abbr),
+                score,
+            })
+        });
+        let teachers = self.teachers.values().filter_map(|t| {
+            fuzzy_score(&query, &normalize(&t.name)).map(|score| SearchResult {
+                hit: SearchHit::Teacher(t.id),
+                label: t.name.clone(),
+                score,
+            })
+        });
+        let classrooms = self.classrooms.values().filter_map(|c| {
+            fuzzy_score(&query, &normalize(&c.full_name)).map(|score| SearchResult {
+                hit: SearchHit::Classroom(c.id),
+                label: c.full_name.clone(),
+                score,
+            })
+        });
+
+        let mut results: Vec<SearchResult> = subjects.chain(teachers).chain(classrooms).collect();
+        results.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.label.cmp(&b.label)));
+        results.truncate(limit);
+        results
+    }
+}
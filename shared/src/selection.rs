@@ -0,0 +1,91 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use anyhow::Result;
+use crate::anyhow;
+use serde::{Deserialize, Serialize};
+
+use crate::definitions::{Entry, EntryType, ExerciseType, SubjectId};
+
+/// Personal choice of subjects and their tutorial/lab groups, saved as JSON
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+pub struct Selection {
+    pub subjects: BTreeMap<SubjectId, GroupSelection>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+pub struct GroupSelection {
+    pub tutorial_groups: BTreeSet<u32>,
+    pub lab_groups: BTreeSet<u32>,
+}
+
+impl Selection {
+    pub fn load(path: &Path) -> Result<Selection> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// $XDG_CONFIG_HOME/timetable/selection.json (or ~/.config/...)
+    pub fn default_path() -> Option<PathBuf> {
+        let config = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config.join("timetable").join("selection.json"))
+    }
+
+    /// Short URL-safe form, e.g. `533.t1.l1.l5-579` (subject 533 with VP S1 and VL S1, S5, subject 579 without groups)
+    pub fn to_code(&self) -> String {
+        self.subjects
+            .iter()
+            .map(|(id, groups)| {
+                let mut parts = vec![id.0.to_string()];
+                parts.extend(groups.tutorial_groups.iter().map(|g| format!("t{g}")));
+                parts.extend(groups.lab_groups.iter().map(|g| format!("l{g}")));
+                parts.join(".")
+            })
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    pub fn from_code(code: &str) -> Result<Selection> {
+        let mut selection = Selection::default();
+        for subject in code.split('-').filter(|s| !s.is_empty()) {
+            let mut parts = subject.split('.');
+            let id = parts
+                .next()
+                .and_then(|id| id.parse::<u32>().ok())
+                .ok_or_else(|| anyhow!("Invalid subject '{subject}' in selection code"))?;
+            let groups = selection.subjects.entry(SubjectId(id)).or_default();
+            for part in parts {
+                let number = |n: &str| n.parse::<u32>().map_err(|_| anyhow!("Invalid group '{part}' in selection code"));
+                if let Some(n) = part.strip_prefix('t') {
+                    groups.tutorial_groups.insert(number(n)?);
+                } else if let Some(n) = part.strip_prefix('l') {
+                    groups.lab_groups.insert(number(n)?);
+                } else {
+                    return Err(anyhow!("Invalid group '{part}' in selection code"));
+                }
+            }
+        }
+        Ok(selection)
+    }
+
+    /// Lectures of selected subjects and exercises of selected groups
+    pub fn contains(&self, entry: &Entry) -> bool {
+        let Some(groups) = self.subjects.get(&entry.subject_id) else {
+            return false;
+        };
+        match &entry.entry_type {
+            EntryType::Lecture => true,
+            EntryType::Exercise(ExerciseType::Tutorial(ids)) => ids.iter().any(|id| groups.tutorial_groups.contains(id)),
+            EntryType::Exercise(ExerciseType::Lab(ids)) => ids.iter().any(|id| groups.lab_groups.contains(id)),
+        }
+    }
+}
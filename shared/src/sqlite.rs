@@ -0,0 +1,415 @@
+//! Export of the [`Database`] into a normalized SQLite file (and loading it back).
+//!
+//! Example: classrooms in use on Wednesday afternoon
+//! ```sql
+//! SELECT DISTINCT c.full_name FROM entries e JOIN classrooms c ON c.id = e.classroom_id
+//! WHERE e.weekday = 3 AND e.start_time >= '12:00';
+//! ```
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::Path;
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use rusqlite::{params, Connection};
+
+use crate::anyhow;
+use crate::db::Database;
+use crate::definitions::{Classroom, ClassroomId, Entry, EntryId, EntryType, ExerciseType, MainProgram, MainProgramId, Minutes, Offering, Program, ProgramId, Semester, StudyLevel, Subject, SubjectId, Teacher, TeacherId, Track};
+
+const SCHEMA: &str = "
+CREATE TABLE main_programs (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    level TEXT NOT NULL CHECK (level IN ('PAP', 'RRP', 'MAG')),
+    year INTEGER NOT NULL
+);
+CREATE TABLE programs (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    full_name TEXT NOT NULL,
+    abbr TEXT NOT NULL,
+    track_abbr TEXT,
+    track_name TEXT,
+    url TEXT NOT NULL,
+    main_program_id INTEGER NOT NULL REFERENCES main_programs(id)
+);
+CREATE TABLE semesters (
+    id INTEGER PRIMARY KEY
+);
+CREATE TABLE subjects (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    abbr TEXT NOT NULL,
+    url TEXT NOT NULL
+);
+CREATE TABLE subject_offerings (
+    subject_id INTEGER NOT NULL REFERENCES subjects(id),
+    main_program_id INTEGER NOT NULL REFERENCES main_programs(id),
+    semester INTEGER NOT NULL REFERENCES semesters(id),
+    PRIMARY KEY (subject_id, main_program_id, semester)
+);
+CREATE TABLE subject_programs (
+    subject_id INTEGER NOT NULL,
+    main_program_id INTEGER NOT NULL,
+    semester INTEGER NOT NULL,
+    program_id INTEGER NOT NULL REFERENCES programs(id),
+    PRIMARY KEY (subject_id, main_program_id, semester, program_id),
+    FOREIGN KEY (subject_id, main_program_id, semester) REFERENCES subject_offerings(subject_id, main_program_id, semester)
+);
+CREATE TABLE subject_groups (
+    subject_id INTEGER NOT NULL REFERENCES subjects(id),
+    group_type TEXT NOT NULL CHECK (group_type IN ('tutorial', 'lab')),
+    group_number INTEGER NOT NULL,
+    PRIMARY KEY (subject_id, group_type, group_number)
+);
+CREATE TABLE teachers (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    url TEXT NOT NULL
+);
+CREATE TABLE classrooms (
+    id INTEGER PRIMARY KEY,
+    full_name TEXT NOT NULL,
+    url TEXT NOT NULL
+);
+CREATE TABLE entries (
+    id TEXT PRIMARY KEY,
+    subject_id INTEGER NOT NULL REFERENCES subjects(id),
+    entry_type TEXT NOT NULL CHECK (entry_type IN ('lecture', 'tutorial', 'lab')),
+    classroom_id INTEGER NOT NULL REFERENCES classrooms(id),
+    date TEXT NOT NULL,
+    weekday INTEGER NOT NULL,
+    week_iso INTEGER NOT NULL,
+    start_time TEXT NOT NULL,
+    end_time TEXT NOT NULL,
+    duration_minutes INTEGER NOT NULL
+);
+CREATE TABLE entry_groups (
+    entry_id TEXT NOT NULL REFERENCES entries(id),
+    group_number INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (entry_id, position)
+);
+CREATE TABLE entry_teachers (
+    entry_id TEXT NOT NULL REFERENCES entries(id),
+    teacher_id INTEGER NOT NULL REFERENCES teachers(id),
+    PRIMARY KEY (entry_id, teacher_id)
+);
+CREATE TABLE work_free_days (
+    date TEXT PRIMARY KEY
+);
+
+CREATE INDEX idx_programs_main_program ON programs(main_program_id);
+CREATE INDEX idx_subject_offerings_main_program_semester ON subject_offerings(main_program_id, semester);
+CREATE INDEX idx_subject_programs_program ON subject_programs(program_id);
+CREATE INDEX idx_entries_subject ON entries(subject_id);
+CREATE INDEX idx_entries_classroom ON entries(classroom_id);
+CREATE INDEX idx_entries_date ON entries(date, start_time);
+CREATE INDEX idx_entries_week ON entries(week_iso, weekday);
+CREATE INDEX idx_entry_teachers_teacher ON entry_teachers(teacher_id);
+";
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const TIME_FORMAT: &str = "%H:%M";
+
+
+/// Writes the database into a new SQLite file, replacing an existing one
+pub fn write(db: &Database, path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+
+    let mut conn = Connection::open(path)?;
+    conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+    conn.execute_batch(SCHEMA)?;
+
+    let tx = conn.transaction()?;
+    {
+        let mut stmt = tx.prepare("INSERT INTO main_programs (id, name, level, year) VALUES (?1, ?2, ?3, ?4)")?;
+        for mp in db.main_programs.values() {
+            stmt.execute(params![mp.id.0, mp.name, mp.level.code(), mp.year])?;
+        }
+
+        let mut stmt = tx.prepare(
+            "INSERT INTO programs (id, name, full_name, abbr, track_abbr, track_name, url, main_program_id)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        )?;
+        for p in db.programs.values() {
+            let track = p.track.as_ref();
+            stmt.execute(params![
+                p.id.0,
+                p.name,
+                p.full_name,
+                p.abbr,
+                track.map(|t| &t.abbr),
+                track.map(|t| &t.name),
+                p.url,
+                p.main_program_id.0,
+            ])?;
+        }
+
+        let mut stmt = tx.prepare("INSERT INTO semesters (id) VALUES (?1)")?;
+        for semester in &db.semesters {
+            stmt.execute(params![semester.0])?;
+        }
+
+        let mut subject_stmt = tx.prepare(
+            "INSERT INTO subjects (id, name, abbr, url) VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        let mut offering_stmt = tx.prepare(
+            "INSERT INTO subject_offerings (subject_id, main_program_id, semester) VALUES (?1, ?2, ?3)",
+        )?;
+        let mut program_stmt = tx.prepare(
+            "INSERT INTO subject_programs (subject_id, main_program_id, semester, program_id) VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        let mut group_stmt = tx.prepare(
+            "INSERT INTO subject_groups (subject_id, group_type, group_number) VALUES (?1, ?2, ?3)",
+        )?;
+        for s in db.subjects.values() {
+            subject_stmt.execute(params![s.id.0, s.name, s.abbr, s.url])?;
+            for o in &s.offerings {
+                offering_stmt.execute(params![s.id.0, o.main_program_id.0, o.semester.0])?;
+                for program_id in &o.program_ids {
+                    program_stmt.execute(params![s.id.0, o.main_program_id.0, o.semester.0, program_id.0])?;
+                }
+            }
+            for group in &s.tutorial_groups {
+                group_stmt.execute(params![s.id.0, "tutorial", group])?;
+            }
+            for group in &s.lab_groups {
+                group_stmt.execute(params![s.id.0, "lab", group])?;
+            }
+        }
+
+        let mut stmt = tx.prepare("INSERT INTO teachers (id, name, url) VALUES (?1, ?2, ?3)")?;
+        for t in db.teachers.values() {
+            stmt.execute(params![t.id.0, t.name, t.url])?;
+        }
+
+        let mut stmt = tx.prepare("INSERT INTO classrooms (id, full_name, url) VALUES (?1, ?2, ?3)")?;
+        for c in db.classrooms.values() {
+            stmt.execute(params![c.id.0, c.full_name, c.url])?;
+        }
+
+        let mut entry_stmt = tx.prepare(
+            "INSERT INTO entries (id, subject_id, entry_type, classroom_id, date, weekday, week_iso, start_time, end_time, duration_minutes)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        )?;
+        let mut group_stmt = tx.prepare(
+            "INSERT INTO entry_groups (entry_id, group_number, position) VALUES (?1, ?2, ?3)",
+        )?;
+        let mut teacher_stmt = tx.prepare("INSERT INTO entry_teachers (entry_id, teacher_id) VALUES (?1, ?2)")?;
+        for e in db.entries() {
+            let id = e.id.to_string();
+            let (kind, groups): (&str, &[u32]) = match &e.entry_type {
+                EntryType::Lecture => ("lecture", &[]),
+                EntryType::Exercise(ExerciseType::Tutorial(groups)) => ("tutorial", groups),
+                EntryType::Exercise(ExerciseType::Lab(groups)) => ("lab", groups),
+            };
+            entry_stmt.execute(params![
+                id,
+                e.subject_id.0,
+                kind,
+                e.classroom_id.0,
+                e.date.format(DATE_FORMAT).to_string(),
+                e.date.weekday().number_from_monday(),
+                e.week_iso,
+                e.start_time.format(TIME_FORMAT).to_string(),
+                e.end_time().format(TIME_FORMAT).to_string(),
+                e.duration.0,
+            ])?;
+            for (position, group) in groups.iter().enumerate() {
+                group_stmt.execute(params![id, group, position])?;
+            }
+            for teacher_id in &e.teacher_ids {
+                teacher_stmt.execute(params![id, teacher_id.0])?;
+            }
+        }
+
+        let mut stmt = tx.prepare("INSERT INTO work_free_days (date) VALUES (?1)")?;
+        for day in &db.work_free_days {
+            stmt.execute(params![day.format(DATE_FORMAT).to_string()])?;
+        }
+    }
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Loads a database previously written with [`write`]
+pub fn read(path: impl AsRef<Path>) -> Result<Database> {
+    let conn = Connection::open(path)?;
+
+    let main_programs_rows = conn
+        .prepare("SELECT id, name, level, year FROM main_programs")?
+        .query_map([], |row| {
+            Ok((MainProgramId(row.get(0)?), row.get::<_, String>(1)?, row.get::<_, String>(2)?, row.get::<_, u8>(3)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let programs: HashMap<ProgramId, Program> = conn
+        .prepare("SELECT id, name, full_name, abbr, track_abbr, track_name, url, main_program_id FROM programs")?
+        .query_map([], |row| {
+            let id = ProgramId(row.get(0)?);
+            let track = match (row.get::<_, Option<String>>(4)?, row.get::<_, Option<String>>(5)?) {
+                (Some(abbr), Some(name)) => Some(Track { abbr, name }),
+                _ => None,
+            };
+            Ok((id, Program {
+                id,
+                name: row.get(1)?,
+                full_name: row.get(2)?,
+                abbr: row.get(3)?,
+                track,
+                url: row.get(6)?,
+                main_program_id: MainProgramId(row.get(7)?),
+            }))
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let main_programs: HashMap<MainProgramId, MainProgram> = main_programs_rows
+        .into_iter()
+        .map(|(id, name, level, year)| {
+            let level = StudyLevel::from_code(&level).ok_or_else(|| anyhow!("Unknown study level '{level}' of main program {}", id.0))?;
+            let program_ids = programs
+                .values()
+                .filter(|p| p.main_program_id == id)
+                .map(|p| p.id)
+                .collect();
+            Ok((id, MainProgram { id, name, level, year, program_ids }))
+        })
+        .collect::<Result<_>>()?;
+
+    let mut semesters = conn
+        .prepare("SELECT id FROM semesters ORDER BY id")?
+        .query_map([], |row| Ok(Semester(row.get(0)?)))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    semesters.dedup();
+
+    let mut subjects: HashMap<SubjectId, Subject> = conn
+        .prepare("SELECT id, name, abbr, url FROM subjects")?
+        .query_map([], |row| {
+            Ok(Subject {
+                id: SubjectId(row.get(0)?),
+                offerings: vec![],
+                name: row.get(1)?,
+                abbr: row.get(2)?,
+                url: row.get(3)?,
+                tutorial_groups: BTreeSet::new(),
+                lab_groups: BTreeSet::new(),
+            })
+        })?
+        .map(|s| s.map(|s| (s.id, s)))
+        .collect::<rusqlite::Result<_>>()?;
+
+    let mut stmt = conn.prepare("SELECT subject_id, main_program_id, semester FROM subject_offerings")?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let subject_id = SubjectId(row.get(0)?);
+        if let Some(subject) = subjects.get_mut(&subject_id) {
+            subject.add_offering(Offering {
+                main_program_id: MainProgramId(row.get(1)?),
+                program_ids: BTreeSet::new(),
+                semester: Semester(row.get(2)?),
+            });
+        }
+    }
+
+    let mut stmt = conn.prepare("SELECT subject_id, main_program_id, semester, program_id FROM subject_programs")?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let subject_id = SubjectId(row.get(0)?);
+        if let Some(subject) = subjects.get_mut(&subject_id) {
+            subject.add_offering(Offering {
+                main_program_id: MainProgramId(row.get(1)?),
+                program_ids: BTreeSet::from([ProgramId(row.get(3)?)]),
+                semester: Semester(row.get(2)?),
+            });
+        }
+    }
+
+    let mut stmt = conn.prepare("SELECT subject_id, group_type, group_number FROM subject_groups")?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let subject_id = SubjectId(row.get(0)?);
+        let group_type: String = row.get(1)?;
+        let group_number: u32 = row.get(2)?;
+        if let Some(subject) = subjects.get_mut(&subject_id) {
+            match group_type.as_str() {
+                "tutorial" => { subject.tutorial_groups.insert(group_number); }
+                "lab" => { subject.lab_groups.insert(group_number); }
+                other => return Err(anyhow!("Unknown group type '{other}' for subject {subject_id:?}")),
+            }
+        }
+    }
+
+    let teachers: HashMap<TeacherId, Teacher> = conn
+        .prepare("SELECT id, name, url FROM teachers")?
+        .query_map([], |row| {
+            Ok(Teacher { id: TeacherId(row.get(0)?), name: row.get(1)?, url: row.get(2)? })
+        })?
+        .map(|t| t.map(|t| (t.id, t)))
+        .collect::<rusqlite::Result<_>>()?;
+
+    let classrooms: HashMap<ClassroomId, Classroom> = conn
+        .prepare("SELECT id, full_name, url FROM classrooms")?
+        .query_map([], |row| {
+            Ok(Classroom { id: ClassroomId(row.get(0)?), full_name: row.get(1)?, url: row.get(2)? })
+        })?
+        .map(|c| c.map(|c| (c.id, c)))
+        .collect::<rusqlite::Result<_>>()?;
+
+    let mut entry_groups: HashMap<String, Vec<u32>> = HashMap::new();
+    let mut stmt = conn.prepare("SELECT entry_id, group_number FROM entry_groups ORDER BY entry_id, position")?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        entry_groups.entry(row.get(0)?).or_default().push(row.get(1)?);
+    }
+
+    let mut entry_teachers: HashMap<String, HashSet<TeacherId>> = HashMap::new();
+    let mut stmt = conn.prepare("SELECT entry_id, teacher_id FROM entry_teachers")?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        entry_teachers.entry(row.get(0)?).or_default().insert(TeacherId(row.get(1)?));
+    }
+
+    // rowid order keeps entries in the order they were written
+    let mut entries = Vec::new();
+    let mut stmt = conn.prepare(
+        "SELECT id, subject_id, entry_type, classroom_id, date, week_iso, start_time, duration_minutes FROM entries ORDER BY rowid",
+    )?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        let kind: String = row.get(2)?;
+        let groups = entry_groups.remove(&id).unwrap_or_default();
+        let entry_type = match kind.as_str() {
+            "lecture" => EntryType::Lecture,
+            "tutorial" => EntryType::Exercise(ExerciseType::Tutorial(groups)),
+            "lab" => EntryType::Exercise(ExerciseType::Lab(groups)),
+            other => return Err(anyhow!("Unknown entry type '{other}' for entry {id}")),
+        };
+        let date: String = row.get(4)?;
+        let start_time: String = row.get(6)?;
+        entries.push(Entry {
+            id: id.parse::<EntryId>().map_err(|e| anyhow!("Invalid entry id '{id}': {e}"))?,
+            subject_id: SubjectId(row.get(1)?),
+            entry_type,
+            teacher_ids: entry_teachers.remove(&id).unwrap_or_default(),
+            classroom_id: ClassroomId(row.get(3)?),
+            date: NaiveDate::parse_from_str(&date, DATE_FORMAT)?,
+            week_iso: row.get(5)?,
+            start_time: NaiveTime::parse_from_str(&start_time, TIME_FORMAT)?,
+            duration: Minutes(row.get(7)?),
+        });
+    }
+
+    let work_free_days = conn
+        .prepare("SELECT date FROM work_free_days ORDER BY date")?
+        .query_map([], |row| row.get::<_, String>(0))?
+        .map(|d| Ok(NaiveDate::parse_from_str(&d?, DATE_FORMAT)?))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Database::new(main_programs, programs, semesters, subjects, teachers, classrooms, work_free_days, entries))
+}
@@ -1,7 +1,9 @@
 use bimap::BiMap;
-use chrono::NaiveTime;
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Timelike};
 use once_cell::sync::Lazy;
 
+use crate::definitions::{Minutes, Semester};
+
 fn t(h: u32, m: u32) -> NaiveTime {
     NaiveTime::from_hms_opt(h, m, 0).unwrap()
 }
@@ -39,21 +41,84 @@ pub static START_TIME_MAP: Lazy<BiMap<&'static str, NaiveTime>> = Lazy::new(|| {
     map
 });
 
-pub static DURATION_MAP: Lazy<BiMap<&'static str, NaiveTime>> = Lazy::new(|| {
+pub static DURATION_MAP: Lazy<BiMap<&'static str, Minutes>> = Lazy::new(|| {
     let mut map = BiMap::new();
-    map.insert("3.85%", t(0, 30));
-    map.insert("7.69%", t(1, 0));
-    map.insert("11.54%", t(1, 30));
-    map.insert("15.38%", t(2, 0));
-    map.insert("19.23%", t(2, 30));
-    map.insert("23.08%", t(3, 0));
-    map.insert("26.92%", t(3, 30));
-    map.insert("30.77%", t(4, 0));
-    map.insert("34.62%", t(4, 30));
-    map.insert("38.46%", t(5, 0));
-    map.insert("42.31%", t(5, 30));
-    map.insert("46.15%", t(6, 0));
-    map.insert("50.00%", t(6, 30));
-    map.insert("53.85%", t(7, 0));
+    map.insert("3.85%", Minutes(30));
+    map.insert("7.69%", Minutes(60));
+    map.insert("11.54%", Minutes(90));
+    map.insert("15.38%", Minutes(120));
+    map.insert("19.23%", Minutes(150));
+    map.insert("23.08%", Minutes(180));
+    map.insert("26.92%", Minutes(210));
+    map.insert("30.77%", Minutes(240));
+    map.insert("34.62%", Minutes(270));
+    map.insert("38.46%", Minutes(300));
+    map.insert("42.31%", Minutes(330));
+    map.insert("46.15%", Minutes(360));
+    map.insert("50.00%", Minutes(390));
+    map.insert("53.85%", Minutes(420));
     map
 });
+
+/// Span of the timetable grid, 7:00 to 20:00
+pub const DAY_START: u32 = 7 * 60;
+pub const DAY_END: u32 = 20 * 60;
+
+/// Vertical position of `time` in the grid, 0.0 at 7:00 and 100.0 at 20:00.
+/// Unlike `START_TIME_MAP` this works for any minute, not only half hours
+pub fn time_to_percent(time: NaiveTime) -> f32 {
+    let minutes = time.hour() * 60 + time.minute();
+    (minutes as f32 - DAY_START as f32) / (DAY_END - DAY_START) as f32 * 100.0
+}
+
+/// Height of `duration` in the grid, see `time_to_percent`
+pub fn duration_to_percent(duration: Minutes) -> f32 {
+    duration.0 as f32 / (DAY_END - DAY_START) as f32 * 100.0
+}
+
+/// True if half-open ranges `[a.0, a.1)` and `[b.0, b.1)` intersect
+pub fn ranges_overlap<T: PartialOrd>(a: (T, T), b: (T, T)) -> bool {
+    a.0 < b.1 && b.0 < a.1
+}
+
+pub fn get_semesters() -> Vec<Semester> {
+    vec![
+        Semester(1),
+        Semester(2),
+    ]
+}
+
+/// First and last day of lectures in the semester
+pub fn get_semester_data(semester: &Semester) -> (NaiveDate, NaiveDate) {
+    match semester {
+        Semester(1) => {(
+            NaiveDate::parse_from_str("2025-10-01", "%Y-%m-%d").unwrap(),
+            NaiveDate::parse_from_str("2026-01-26", "%Y-%m-%d").unwrap(),
+        )},
+        Semester(2) => {(
+            NaiveDate::parse_from_str("2026-02-09", "%Y-%m-%d").unwrap(),
+            NaiveDate::parse_from_str("2026-06-01", "%Y-%m-%d").unwrap(),
+        )},
+        _ => panic!("Invalid semester {semester:?}"),
+    }
+}
+
+/// Semester whose lecture period contains `date`
+pub fn semester_of(date: NaiveDate) -> Option<Semester> {
+    get_semesters().into_iter().find(|semester| {
+        let (start, end) = get_semester_data(semester);
+        (start..=end).contains(&date)
+    })
+}
+
+/// Mondays of the weeks that overlap the semester
+pub fn semester_mondays(semester: &Semester) -> Vec<NaiveDate> {
+    let (start, end) = get_semester_data(semester);
+    let mut monday = start - Duration::days(start.weekday().num_days_from_monday() as i64);
+    let mut mondays = vec![];
+    while monday <= end {
+        mondays.push(monday);
+        monday += Duration::weeks(1);
+    }
+    mondays
+}
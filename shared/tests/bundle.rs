@@ -0,0 +1,148 @@
+use std::collections::{BTreeSet, HashMap};
+use chrono::{Datelike, NaiveDate, NaiveTime};
+
+use shared::{db::Database, definitions::{Classroom, ClassroomId, Entry, EntryId, EntryType, ExerciseType, MainProgram, MainProgramId, Minutes, Offering, Program, ProgramId, Semester, StudyLevel, Subject, SubjectId, Teacher, TeacherId, Track}};
+
+fn entry(subject: u32, entry_type: EntryType, teachers: &[u32], classroom: u32, date: &str, start: &str, minutes: u32) -> Entry {
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+    let start_time = NaiveTime::parse_from_str(start, "%H:%M").unwrap();
+    Entry {
+        id: EntryId::new(SubjectId(subject), &entry_type, date, start_time, ClassroomId(classroom)),
+        subject_id: SubjectId(subject),
+        entry_type,
+        teacher_ids: teachers.iter().map(|t| TeacherId(*t)).collect(),
+        classroom_id: ClassroomId(classroom),
+        date,
+        week_iso: date.iso_week().week() as i32,
+        start_time,
+        duration: Minutes(minutes),
+    }
+}
+
+fn test_db() -> Database {
+    let subject = |id: u32, abbr: &str, offerings: Vec<Offering>| Subject {
+        id: SubjectId(id),
+        offerings,
+        name: format!("Subject {abbr}"),
+        abbr: abbr.to_string(),
+        url: format!("/subject/{id}/"),
+        tutorial_groups: BTreeSet::from([1, 2]),
+        lab_groups: BTreeSet::from([3]),
+    };
+    let offering = |main_program: u32, program: u32, semester: u8| Offering {
+        main_program_id: MainProgramId(main_program),
+        program_ids: BTreeSet::from([ProgramId(program)]),
+        semester: Semester(semester),
+    };
+    let teacher = |id: u32, name: &str| (TeacherId(id), Teacher { id: TeacherId(id), name: name.into(), url: format!("/teacher/{id}/") });
+    let classroom = |id: u32, name: &str| (ClassroomId(id), Classroom { id: ClassroomId(id), full_name: name.into(), url: format!("/classroom/{id}/") });
+
+    Database::new(
+        HashMap::from([
+            (MainProgramId(0), MainProgram::new(MainProgramId(0), "MAG-1".into(), BTreeSet::from([ProgramId(85)])).unwrap()),
+            (MainProgramId(2), MainProgram::new(MainProgramId(2), "RRP-1".into(), BTreeSet::from([ProgramId(80)])).unwrap()),
+        ]),
+        HashMap::from([
+            (ProgramId(85), Program::new(ProgramId(85), "Energetsko strojništvo (MAG-1) (ENS-MAG)".into(), "/layer_one/85/".into(), MainProgramId(0)).unwrap()),
+            (ProgramId(80), Program::new(ProgramId(80), "RRP-1 (RRP-1) (RRP-1)".into(), "/layer_one/80/".into(), MainProgramId(2)).unwrap()),
+        ]),
+        vec![Semester(1), Semester(2)],
+        HashMap::from([
+            (SubjectId(1), subject(1, "TDZM", vec![offering(0, 85, 1), offering(2, 80, 1)])),
+            (SubjectId(2), subject(2, "MLTR", vec![offering(0, 85, 2)])),
+        ]),
+        HashMap::from([teacher(7, "Ana Novak"), teacher(3, "Bor Kos"), teacher(12, "Cene Lah")]),
+        HashMap::from([classroom(194, "P1"), classroom(20, "VP2")]),
+        vec![NaiveDate::from_ymd_opt(2025, 10, 31).unwrap(), NaiveDate::from_ymd_opt(2025, 12, 25).unwrap()],
+        vec![
+            entry(1, EntryType::Lecture, &[7, 3], 194, "2025-10-06", "08:15", 90),
+            entry(1, EntryType::Exercise(ExerciseType::Tutorial(vec![1, 2])), &[12], 20, "2025-10-07", "10:00", 45),
+            entry(2, EntryType::Exercise(ExerciseType::Lab(vec![3])), &[], 20, "2026-03-02", "14:30", 180),
+        ],
+    )
+}
+
+#[test]
+fn bundle_round_trip() {
+    let db = test_db();
+    let bytes = db.to_bundle().unwrap();
+    assert_eq!(Database::from_bundle(&bytes).unwrap(), db);
+    // same database, same bytes
+    assert_eq!(db.to_bundle().unwrap(), bytes);
+}
+
+// Stored level, year and track are kept, the label isn't parsed again
+fn with_unparseable_program(mut db: Database) -> Database {
+    db.main_programs.insert(MainProgramId(9), MainProgram {
+        id: MainProgramId(9),
+        name: "Doktorski študij".into(),
+        level: StudyLevel::Msc,
+        year: 3,
+        program_ids: BTreeSet::from([ProgramId(90)]),
+    });
+    db.programs.insert(ProgramId(90), Program {
+        id: ProgramId(90),
+        name: "Strojništvo".into(),
+        full_name: "Strojništvo".into(),
+        abbr: "DR".into(),
+        track: Some(Track { abbr: "DR".into(), name: "Strojništvo".into() }),
+        url: "/layer_one/90/".into(),
+        main_program_id: MainProgramId(9),
+    });
+    db
+}
+
+#[test]
+fn unparseable_program_round_trip() {
+    let db = with_unparseable_program(test_db());
+    let bytes = db.to_bundle().unwrap();
+    assert_eq!(Database::from_bundle(&bytes).unwrap(), db);
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn unparseable_program_sqlite_round_trip() {
+    let db = with_unparseable_program(test_db());
+    let path = std::env::temp_dir().join(format!("timetable-test-{}.sqlite", std::process::id()));
+    db.to_sqlite(&path).unwrap();
+    let read = Database::from_sqlite(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(read.unwrap(), db);
+}
+
+#[test]
+fn stored_entry_ids_are_kept() {
+    let mut db = test_db();
+    let mut entries = db.entries().to_vec();
+    entries[0].id = EntryId(42);
+    db.set_entries(entries);
+
+    let decoded = Database::from_bundle(&db.to_bundle().unwrap()).unwrap();
+    assert_eq!(decoded.entry(&EntryId(42)), Some(&db.entries()[0]));
+}
+
+// Saved selections and overrides refer to these, changing the hash breaks them
+#[test]
+fn entry_ids_are_pinned() {
+    let db = test_db();
+    let ids: Vec<String> = db.entries().iter().map(|e| e.id.to_string()).collect();
+    assert_eq!(ids, ["d0a145d35e61aef6", "ab50660eb1e976b6", "6e0bf8ec726e0818"]);
+}
+
+#[test]
+fn too_many_teachers_is_an_error() {
+    let mut db = test_db();
+    db.teachers = (0..=u16::MAX as u32 + 1)
+        .map(|id| (TeacherId(id), Teacher { id: TeacherId(id), name: String::new(), url: String::new() }))
+        .collect();
+    assert!(db.to_bundle().is_err());
+}
+
+#[test]
+fn long_duration_is_an_error() {
+    let mut db = test_db();
+    let mut entries = db.entries().to_vec();
+    entries[0].duration = Minutes(u16::MAX as u32 + 1);
+    db.set_entries(entries);
+    assert!(db.to_bundle().is_err());
+}
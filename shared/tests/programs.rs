@@ -0,0 +1,74 @@
+use shared::{definitions::{StudyLevel, Track}, programs::{parse_main_program, ProgramLabel, ProgramParseError}};
+
+fn track(abbr: &str, name: &str) -> Option<Track> {
+    Some(Track { abbr: abbr.into(), name: name.into() })
+}
+
+#[test]
+fn program_labels() {
+    let cases = [
+        ("Procesno strojništvo (MAG-2) (PCS-MAG)", "Procesno strojništvo", "MAG-2", "PCS-MAG", track("PCS", "Procesno strojništvo")),
+        ("Energetsko strojništvo (MAG-1) (ENS-MAG)", "Energetsko strojništvo", "MAG-1", "ENS-MAG", track("ENS", "Energetsko strojništvo")),
+        ("Proizvodno strojništvo (PAP-2) (PPL-PAP)", "Proizvodno strojništvo", "PAP-2", "PPL-PAP", track("PPL", "Proizvodno strojništvo")),
+        ("Konstruiranje (RRP-3) (KON-RRP)", "Konstruiranje", "RRP-3", "KON-RRP", track("KON", "Konstruiranje")),
+        // the whole year, no track
+        ("RRP-1 (RRP-1) (RRP-1)", "RRP-1", "RRP-1", "RRP-1", None),
+        // abbreviation without the level suffix is kept whole
+        ("Mehatronika ( MAG-1 ) (MEH)", "Mehatronika", "MAG-1", "MEH", track("MEH", "Mehatronika")),
+    ];
+    for (text, name, main_program, abbr, expected_track) in cases {
+        let label = ProgramLabel::parse(text).unwrap();
+        assert_eq!(label, ProgramLabel { name: name.into(), main_program: main_program.into(), abbr: abbr.into() }, "{text}");
+        assert_eq!(label.track().unwrap(), expected_track, "{text}");
+    }
+}
+
+#[test]
+fn program_label_errors() {
+    assert_eq!(ProgramLabel::parse("Strojništvo"), Err(ProgramParseError::MissingMainProgram("Strojništvo".into())));
+    assert_eq!(ProgramLabel::parse("Strojništvo (MAG-1)"), Err(ProgramParseError::MissingAbbr("Strojništvo (MAG-1)".into())));
+    assert_eq!(ProgramLabel::parse("Strojništvo (MAG-1) (STR-MAG"), Err(ProgramParseError::MissingAbbr("Strojništvo (MAG-1) (STR-MAG".into())));
+    // the track needs a known level
+    let label = ProgramLabel::parse("Strojništvo (DR-1) (STR-DR)").unwrap();
+    assert_eq!(label.track(), Err(ProgramParseError::UnknownLevel("DR-1".into())));
+}
+
+#[test]
+fn main_programs() {
+    let cases = [
+        ("PAP-1", StudyLevel::BscProfessional, 1),
+        ("PAP-3", StudyLevel::BscProfessional, 3),
+        ("RRP-2", StudyLevel::BscAcademic, 2),
+        ("MAG-1", StudyLevel::Msc, 1),
+        ("MAG-2", StudyLevel::Msc, 2),
+    ];
+    for (name, level, year) in cases {
+        assert_eq!(parse_main_program(name), Ok((level, year)), "{name}");
+    }
+}
+
+#[test]
+fn main_program_errors() {
+    let cases = [
+        ("DR-1", ProgramParseError::UnknownLevel("DR-1".into())),
+        ("mag-1", ProgramParseError::UnknownLevel("mag-1".into())),
+        ("MAG", ProgramParseError::InvalidYear("MAG".into())),
+        ("MAG-0", ProgramParseError::InvalidYear("MAG-0".into())),
+        ("MAG-x", ProgramParseError::InvalidYear("MAG-x".into())),
+        ("MAG-", ProgramParseError::InvalidYear("MAG-".into())),
+    ];
+    for (name, error) in cases {
+        assert_eq!(parse_main_program(name), Err(error), "{name}");
+    }
+}
+
+#[test]
+fn study_level_codes() {
+    for level in StudyLevel::ALL {
+        assert_eq!(StudyLevel::from_code(level.code()), Some(level));
+    }
+    assert_eq!(StudyLevel::from_code("PAP"), Some(StudyLevel::BscProfessional));
+    assert_eq!(StudyLevel::from_code("RRP"), Some(StudyLevel::BscAcademic));
+    assert_eq!(StudyLevel::from_code("MAG"), Some(StudyLevel::Msc));
+    assert_eq!(StudyLevel::from_code("DR"), None);
+}
@@ -0,0 +1,203 @@
+use std::path::PathBuf;
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use crossterm::event::KeyCode;
+
+use shared::{db::Database, definitions::{Entry, MainProgramId, Semester, Subject, SubjectId}, selection::{GroupSelection, Selection}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    Grid,
+    Subjects,
+}
+
+/// One row of the subject panel, groups are listed under selected subjects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelItem {
+    Subject(SubjectId),
+    Tutorial(SubjectId, u32),
+    Lab(SubjectId, u32),
+}
+
+pub struct App {
+    pub db: Database,
+    pub main_programs: Vec<(MainProgramId, String)>,
+    pub main_program: usize, // index into main_programs
+    pub semester: Semester,
+    pub year: i32,
+    pub week: i32,
+    pub focus: Focus,
+    pub cursor: usize,
+    pub selection: Selection,
+    pub selection_path: PathBuf,
+    pub status: String,
+    pub should_quit: bool,
+}
+
+impl App {
+    pub fn new(db: Database, selection: Selection, selection_path: PathBuf) -> Self {
+        let mut main_programs: Vec<(MainProgramId, String)> = db.main_programs
+            .values()
+            .map(|mp| (mp.id, mp.name.clone()))
+            .collect();
+        main_programs.sort_by_key(|(id, _)| id.0);
+
+        let today = Local::now().date_naive().iso_week();
+        let semester = db.semesters.first().copied().unwrap_or(Semester(1));
+
+        App {
+            db,
+            main_programs,
+            main_program: 0,
+            semester,
+            year: today.year(),
+            week: today.week() as i32,
+            focus: Focus::Grid,
+            cursor: 0,
+            selection,
+            selection_path,
+            status: String::new(),
+            should_quit: false,
+        }
+    }
+
+    pub fn main_program_id(&self) -> Option<MainProgramId> {
+        self.main_programs.get(self.main_program).map(|(id, _)| *id)
+    }
+
+    pub fn main_program_name(&self) -> &str {
+        self.main_programs.get(self.main_program).map(|(_, name)| name.as_str()).unwrap_or("-")
+    }
+
+    pub fn monday(&self) -> NaiveDate {
+        NaiveDate::from_isoywd_opt(self.year, self.week as u32, Weekday::Mon).expect("valid ISO week date")
+    }
+
+    pub fn subjects(&self) -> Vec<Subject> {
+        let Some(id) = self.main_program_id() else {
+            return vec![];
+        };
+        let mut subjects = self.db.subjects_for_main_program_and_semester(&id, self.semester);
+        subjects.sort_by_key(|s| s.id.0);
+        subjects
+    }
+
+    pub fn panel_items(&self) -> Vec<PanelItem> {
+        let mut items = Vec::new();
+        for subject in self.subjects() {
+            items.push(PanelItem::Subject(subject.id));
+            if self.selection.subjects.contains_key(&subject.id) {
+                items.extend(subject.tutorial_groups.iter().map(|g| PanelItem::Tutorial(subject.id, *g)));
+                items.extend(subject.lab_groups.iter().map(|g| PanelItem::Lab(subject.id, *g)));
+            }
+        }
+        items
+    }
+
+    /// Selected entries of the shown week and main program
+    pub fn week_entries(&self) -> Vec<&Entry> {
+        let Some((id, _)) = self.main_programs.get(self.main_program) else {
+            return vec![];
+        };
+        self.db.query()
+            .main_program(id)
+            .semester(self.semester)
+            .week(self.week)
+            .run()
+            .into_iter()
+            .filter(|e| e.date.iso_week().year() == self.year)
+            .filter(|e| self.selection.contains(e))
+            .collect()
+    }
+
+    pub fn on_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    Focus::Grid => Focus::Subjects,
+                    Focus::Subjects => Focus::Grid,
+                };
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.shift_week(-1),
+            KeyCode::Right | KeyCode::Char('l') => self.shift_week(1),
+            KeyCode::Char('t') => {
+                let today = Local::now().date_naive().iso_week();
+                self.year = today.year();
+                self.week = today.week() as i32;
+            }
+            KeyCode::Char('p') => self.cycle_main_program(),
+            KeyCode::Char('s') => self.cycle_semester(),
+            KeyCode::Up | KeyCode::Char('k') if self.focus == Focus::Subjects => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.focus == Focus::Subjects => {
+                let len = self.panel_items().len();
+                if self.cursor + 1 < len {
+                    self.cursor += 1;
+                }
+            }
+            KeyCode::Char(' ') | KeyCode::Enter if self.focus == Focus::Subjects => self.toggle_at_cursor(),
+            _ => {}
+        }
+    }
+
+    fn shift_week(&mut self, weeks: i64) {
+        let monday = self.monday() + Duration::weeks(weeks);
+        let iso = monday.iso_week();
+        self.year = iso.year();
+        self.week = iso.week() as i32;
+    }
+
+    fn cycle_main_program(&mut self) {
+        if !self.main_programs.is_empty() {
+            self.main_program = (self.main_program + 1) % self.main_programs.len();
+            self.cursor = 0;
+        }
+    }
+
+    fn cycle_semester(&mut self) {
+        let semesters = &self.db.semesters;
+        if let Some(i) = semesters.iter().position(|s| *s == self.semester) {
+            self.semester = semesters[(i + 1) % semesters.len()];
+            self.cursor = 0;
+        }
+    }
+
+    fn toggle_at_cursor(&mut self) {
+        let Some(item) = self.panel_items().get(self.cursor).copied() else {
+            return;
+        };
+        match item {
+            PanelItem::Subject(id) => {
+                if self.selection.subjects.remove(&id).is_none() {
+                    // Like the web app, a newly shown subject has all groups on
+                    if let Some(subject) = self.db.subjects.get(&id) {
+                        self.selection.subjects.insert(id, GroupSelection {
+                            tutorial_groups: subject.tutorial_groups.clone(),
+                            lab_groups: subject.lab_groups.clone(),
+                        });
+                    }
+                }
+            }
+            PanelItem::Tutorial(id, group) => {
+                if let Some(groups) = self.selection.subjects.get_mut(&id) {
+                    if !groups.tutorial_groups.remove(&group) {
+                        groups.tutorial_groups.insert(group);
+                    }
+                }
+            }
+            PanelItem::Lab(id, group) => {
+                if let Some(groups) = self.selection.subjects.get_mut(&id) {
+                    if !groups.lab_groups.remove(&group) {
+                        groups.lab_groups.insert(group);
+                    }
+                }
+            }
+        }
+
+        self.status = match self.selection.save(&self.selection_path) {
+            Ok(()) => format!("Saved to {}", self.selection_path.display()),
+            Err(e) => format!("Cannot save selection: {e}"),
+        };
+    }
+}
@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+use std::time::Duration;
+use anyhow::{Result, anyhow};
+use clap::Parser;
+use crossterm::event::{self, Event, KeyEventKind};
+use ratatui::DefaultTerminal;
+
+use shared::{data::load_database, selection::Selection};
+
+pub mod app;
+pub mod ui;
+
+use app::App;
+
+
+/// Browse the timetable week by week in the terminal
+#[derive(Parser)]
+#[command(name = "timetable-tui")]
+struct Args {
+    /// Directory with timetable.bin or *.json data
+    #[arg(long, default_value = "static")]
+    data: PathBuf,
+
+    /// Where the subject/group selection is saved (default: ~/.config/timetable/selection.json)
+    #[arg(long)]
+    selection: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let db = load_database(&args.data)
+        .map_err(|e| anyhow!("Cannot load data from {}: {e}", args.data.display()))?;
+
+    let selection_path = args.selection
+        .or_else(Selection::default_path)
+        .ok_or_else(|| anyhow!("No HOME set, pass --selection <FILE>"))?;
+    let selection = if selection_path.exists() {
+        Selection::load(&selection_path)?
+    } else {
+        Selection::default()
+    };
+
+    let mut app = App::new(db, selection, selection_path);
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, &mut app);
+    ratatui::restore();
+    result
+}
+
+fn run(terminal: &mut DefaultTerminal, app: &mut App) -> Result<()> {
+    while !app.should_quit {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+
+        if event::poll(Duration::from_millis(250))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.on_key(key.code);
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use chrono::{Datelike, Duration, NaiveTime, Timelike};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use shared::{definitions::{Entry, SubjectId}, layout::arrange_columns};
+
+use crate::app::{App, Focus, PanelItem};
+
+const DAYS: [&str; 5] = ["Ponedeljek", "Torek", "Sreda", "Četrtek", "Petek"];
+const FIRST_HOUR: u32 = 7;
+const HALF_HOURS: u16 = 26; // 7:00 - 20:00
+const GUTTER: u16 = 6;
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [title, body, help] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ]).areas(frame.area());
+    let [panel, grid] = Layout::horizontal([
+        Constraint::Length(30),
+        Constraint::Min(0),
+    ]).areas(body);
+
+    let colors = subject_colors(app);
+
+    let monday = app.monday();
+    let friday = monday + Duration::days(4);
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled(" TIMETABLE ", Style::new().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(
+                " {} · {} semester · {} - {}",
+                app.main_program_name(),
+                app.semester.0,
+                monday.format("%d.%m.%Y"),
+                friday.format("%d.%m.%Y"),
+            )),
+        ])),
+        title,
+    );
+
+    draw_panel(frame, app, &colors, panel);
+    draw_grid(frame, app, &colors, grid);
+
+    let keys = "←/→ week  t today  p program  s semester  Tab panel  ↑/↓ move  Space toggle  q quit";
+    let help_text = if app.status.is_empty() { keys.to_string() } else { format!("{keys}  │ {}", app.status) };
+    frame.render_widget(Paragraph::new(help_text).style(Style::new().fg(Color::DarkGray)), help);
+}
+
+/// Evenly spread hues over the shown subjects, like the web app
+fn subject_colors(app: &App) -> HashMap<SubjectId, Color> {
+    let subjects = app.subjects();
+    let n = subjects.len().max(1);
+    subjects
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.id, hsl(i as f32 / n as f32 * 360.0, 0.6, 0.55)))
+        .collect()
+}
+
+fn hsl(h: f32, s: f32, l: f32) -> Color {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h_prime = h / 60.0;
+    let x = c * (1.0 - ((h_prime % 2.0) - 1.0).abs());
+    let (r, g, b) = match h_prime as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = l - c / 2.0;
+    let to_u8 = |v: f32| ((v + m) * 255.0).round() as u8;
+    Color::Rgb(to_u8(r), to_u8(g), to_u8(b))
+}
+
+fn draw_panel(frame: &mut Frame, app: &App, colors: &HashMap<SubjectId, Color>, area: Rect) {
+    let items: Vec<ListItem> = app.panel_items()
+        .iter()
+        .map(|item| match item {
+            PanelItem::Subject(id) => {
+                let subject = &app.db.subjects[id];
+                let checked = if app.selection.subjects.contains_key(id) { "[x]" } else { "[ ]" };
+                let color = colors.get(id).copied().unwrap_or(Color::Gray);
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{checked} ")),
+                    Span::styled(subject.abbr.clone(), Style::new().fg(color).add_modifier(Modifier::BOLD)),
+                    Span::raw(format!(" {}", subject.name)),
+                ]))
+            }
+            PanelItem::Tutorial(id, group) | PanelItem::Lab(id, group) => {
+                let (label, on) = match item {
+                    PanelItem::Tutorial(..) => ("VP", app.selection.subjects[id].tutorial_groups.contains(group)),
+                    _ => ("VL", app.selection.subjects[id].lab_groups.contains(group)),
+                };
+                let checked = if on { "[x]" } else { "[ ]" };
+                ListItem::new(format!("    {checked} {label} S{group}"))
+            }
+        })
+        .collect();
+
+    let border_style = if app.focus == Focus::Subjects {
+        Style::new().fg(Color::Yellow)
+    } else {
+        Style::new()
+    };
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(" Subjects ").border_style(border_style))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+
+    let mut state = ListState::default();
+    if app.focus == Focus::Subjects {
+        state.select(Some(app.cursor));
+    }
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_grid(frame: &mut Frame, app: &App, colors: &HashMap<SubjectId, Color>, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).border_style(if app.focus == Focus::Grid {
+        Style::new().fg(Color::Yellow)
+    } else {
+        Style::new()
+    });
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width <= GUTTER + 5 || inner.height < 2 {
+        return;
+    }
+
+    let rows_per_slot = ((inner.height - 1) / HALF_HOURS).max(1);
+    let day_width = (inner.width - GUTTER) / 5;
+    let grid_top = inner.y + 1;
+    let monday = app.monday();
+
+    // Day headers, work-free days are shaded
+    for (i, name) in DAYS.iter().enumerate() {
+        let date = monday + Duration::days(i as i64);
+        let work_free = app.db.work_free_days.contains(&date);
+        let style = if work_free {
+            Style::new().fg(Color::Black).bg(Color::DarkGray)
+        } else {
+            Style::new().add_modifier(Modifier::BOLD)
+        };
+        let x = inner.x + GUTTER + i as u16 * day_width;
+        let text = format!("{name} {:02}.{:02}.", date.day(), date.month());
+        frame.render_widget(Paragraph::new(text).style(style), Rect::new(x, inner.y, day_width, 1));
+    }
+
+    // Hour marks
+    for slot in (0..HALF_HOURS).step_by(2) {
+        let y = grid_top + slot * rows_per_slot;
+        if y >= inner.bottom() {
+            break;
+        }
+        let hour = FIRST_HOUR + slot as u32 / 2;
+        frame.render_widget(
+            Paragraph::new(format!("{hour:>2}:00")).style(Style::new().fg(Color::DarkGray)),
+            Rect::new(inner.x, y, GUTTER, 1),
+        );
+    }
+
+    let entries: Vec<&Entry> = app.week_entries();
+    let placements = arrange_columns(&entries);
+
+    for (entry, placement) in entries.iter().zip(placements) {
+        let day = entry.date.weekday().num_days_from_monday() as u16;
+        if day > 4 {
+            continue;
+        }
+        let column_width = day_width / placement.total_columns as u16;
+        let x = inner.x + GUTTER + day * day_width + placement.column as u16 * column_width;
+        let y = grid_top + half_hours_since_start(entry.start_time) * rows_per_slot;
+        let height = (entry.duration.0 / 30) as u16 * rows_per_slot;
+        let rect = Rect::new(x, y, column_width.saturating_sub(1), height).intersection(inner);
+        if rect.is_empty() {
+            continue;
+        }
+
+        let subject = app.db.subjects.get(&entry.subject_id);
+        let classroom = app.db.classrooms.get(&entry.classroom_id).map(|c| c.full_name.as_str()).unwrap_or("");
+        let color = colors.get(&entry.subject_id).copied().unwrap_or(Color::Gray);
+        let lines = vec![
+            Line::from(Span::styled(
+                subject.map(|s| s.abbr.as_str()).unwrap_or("?").to_string(),
+                Style::new().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(entry.entry_type.to_string()),
+            Line::from(classroom.to_string()),
+        ];
+        frame.render_widget(
+            Paragraph::new(lines).style(Style::new().fg(Color::Black).bg(color)),
+            rect,
+        );
+    }
+}
+
+fn half_hours_since_start(time: NaiveTime) -> u16 {
+    let minutes = (time.hour() * 60 + time.minute()).saturating_sub(FIRST_HOUR * 60);
+    (minutes / 30) as u16
+}